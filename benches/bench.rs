@@ -356,6 +356,106 @@ impl_streamed_bench!(
     streamed_16384bytes, 16384
 );
 
+mod wyhash64_batch {
+    use super::*;
+    fn bench(c: &mut Criterion) {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let content: Vec<u8> = (0..64 * 1024)
+            .map(|i| (seed >> ((i % 8) * 8)) as u8 ^ i as u8)
+            .collect();
+        let keys: Vec<&[u8]> = (0..1024)
+            .map(|i| &content[i * 64..i * 64 + 8 + i % 57])
+            .collect();
+        let hasher = WyHash64::with_seed(seed);
+        let mut output = vec![0u64; keys.len()];
+
+        c.bench_function("wyhash64_batch::per_key_1024keys", |b| {
+            b.iter(|| {
+                for (out, key) in output.iter_mut().zip(&keys) {
+                    *out = hasher.hash(key);
+                }
+                black_box(&output);
+            });
+        });
+        c.bench_function("wyhash64_batch::batch_1024keys", |b| {
+            b.iter(|| {
+                hasher.hash_batch(&keys, &mut output);
+                black_box(&output);
+            });
+        });
+    }
+    criterion_group!(
+        name=benches;
+        config=Criterion::default()
+            .sample_size(100)
+            .warm_up_time(std::time::Duration::from_millis(5))
+            .measurement_time(std::time::Duration::from_millis(50));
+        targets=bench
+    );
+}
+
+mod integer_keys {
+    use super::*;
+    use std::hash::BuildHasher;
+
+    fn bench_hasher<S: BuildHasher>(c: &mut Criterion, name: &str, builder: &S, keys: &[u64]) {
+        c.bench_function(
+            format!("integer_keys::{}_u64_1024keys", name).as_str(),
+            |b| {
+                b.iter(|| {
+                    for key in keys {
+                        black_box(builder.hash_one(black_box(*key)));
+                    }
+                });
+            },
+        );
+        c.bench_function(
+            format!("integer_keys::{}_u64_pair_1024keys", name).as_str(),
+            |b| {
+                b.iter(|| {
+                    for key in keys {
+                        black_box(builder.hash_one(black_box((*key, !*key))));
+                    }
+                });
+            },
+        );
+    }
+
+    fn bench(c: &mut Criterion) {
+        let mut seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let keys: Vec<u64> = (0..1024)
+            .map(|_| {
+                seed = WyHash64::hash_u64_pair(seed, 1);
+                seed
+            })
+            .collect();
+
+        bench_hasher(c, "wyhash64", &WyHash64::with_seed(seed), &keys);
+        bench_hasher(c, "wyhash32", &WyHash32::with_seed(seed), &keys);
+        bench_hasher(c, "fxhash", &rustc_hash::FxBuildHasher, &keys);
+        bench_hasher(
+            c,
+            "siphash",
+            &std::collections::hash_map::RandomState::new(),
+            &keys,
+        );
+    }
+    criterion_group!(
+        name=benches;
+        config=Criterion::default()
+            .sample_size(100)
+            .warm_up_time(std::time::Duration::from_millis(5))
+            .measurement_time(std::time::Duration::from_millis(50));
+        targets=bench
+    );
+}
+
 criterion_main!(
     wyhash64_oneshot::benches,
     wyhash64_hasher::benches,
@@ -369,4 +469,6 @@ criterion_main!(
     wyhash32condom_oneshot::benches,
     wyhash32condom_hasher::benches,
     wyhash32condom_streamed::benches,
+    wyhash64_batch::benches,
+    integer_keys::benches,
 );
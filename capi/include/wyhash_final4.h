@@ -0,0 +1,397 @@
+/* The C ABI of wyhash-final4, compatible with the reference wyhash.h. */
+
+#ifndef WYHASH_FINAL4_H
+#define WYHASH_FINAL4_H
+
+/* This file is generated by cbindgen from wyhash-final4-capi, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A streamed hasher of [`WyHash32Condom`]
+ */
+typedef struct WyHash32CondomStream WyHash32CondomStream;
+
+/**
+ * A streamed hasher of [`WyHash32`]
+ */
+typedef struct WyHash32Stream WyHash32Stream;
+
+/**
+ * A streamed hasher of [`WyHash64Condom`]
+ */
+typedef struct WyHash64CondomStream WyHash64CondomStream;
+
+/**
+ * A streamed hasher of [`WyHash64`]
+ */
+typedef struct WyHash64Stream WyHash64Stream;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ *
+ * Hash `len` bytes at `key` with `seed` and the 4 words of `secret`, like `wyhash()` of
+ * `wyhash.h` with the default settings
+ *
+ * # Safety
+ *
+ * `key` must point to `len` readable bytes, and `secret` must be either null for the default
+ * secret or point to 4 readable words.
+ *
+ */
+uint64_t wyhash(const void *key, size_t len, uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * Generate a secret from `seed` into the 4 words at `secret`, like `make_secret()` of
+ * `wyhash.h` with the default settings
+ *
+ * # Safety
+ *
+ * `secret` must point to 4 writable words.
+ *
+ */
+void make_secret(uint64_t seed, uint64_t *secret);
+
+/**
+ *
+ * Advance the state at `seed` and return a random number, like `wyrand()` of `wyhash.h` with
+ * the default settings
+ *
+ * # Safety
+ *
+ * `seed` must point to a readable and writable word.
+ *
+ */
+uint64_t wyrand(uint64_t *seed);
+
+/**
+ *
+ * [`wyhash`] of the [`WyHash64`] variant
+ *
+ * # Safety
+ *
+ * See [`wyhash`].
+ *
+ */
+uint64_t wyhash_64(const void *key, size_t len, uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * [`make_secret`] of the [`WyHash64`] variant
+ *
+ * # Safety
+ *
+ * See [`make_secret`].
+ *
+ */
+void make_secret_64(uint64_t seed, uint64_t *secret);
+
+/**
+ *
+ * [`wyrand`] of the [`WyHash64`] variant
+ *
+ * # Safety
+ *
+ * See [`wyrand`].
+ *
+ */
+uint64_t wyrand_64(uint64_t *seed);
+
+/**
+ *
+ * Create a streamed hasher of the [`WyHash64`] variant with `seed` and `secret`, which must be
+ * released with [`wyhash_64_stream_finish`] or [`wyhash_64_stream_free`]
+ *
+ * # Safety
+ *
+ * `secret` must be either null for the default secret or point to 4 readable words.
+ *
+ */
+WyHash64Stream *wyhash_64_stream_new(uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * Feed `len` bytes at `key` to the streamed hasher
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_64_stream_new`] and not yet released, and `key` must
+ * point to `len` readable bytes.
+ *
+ */
+void wyhash_64_stream_update(WyHash64Stream *stream, const void *key, size_t len);
+
+/**
+ *
+ * Release the streamed hasher and return the hash of all the bytes fed to it
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_64_stream_new`] and not yet released.
+ *
+ */
+uint64_t wyhash_64_stream_finish(WyHash64Stream *stream);
+
+/**
+ *
+ * Release the streamed hasher without finishing it
+ *
+ * # Safety
+ *
+ * `stream` must be either null, or created by [`wyhash_64_stream_new`] and not yet released.
+ *
+ */
+void wyhash_64_stream_free(WyHash64Stream *stream);
+
+/**
+ *
+ * [`wyhash`] of the [`WyHash64Condom`] variant
+ *
+ * # Safety
+ *
+ * See [`wyhash`].
+ *
+ */
+uint64_t wyhash_64_condom(const void *key, size_t len, uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * [`make_secret`] of the [`WyHash64Condom`] variant
+ *
+ * # Safety
+ *
+ * See [`make_secret`].
+ *
+ */
+void make_secret_64_condom(uint64_t seed, uint64_t *secret);
+
+/**
+ *
+ * [`wyrand`] of the [`WyHash64Condom`] variant
+ *
+ * # Safety
+ *
+ * See [`wyrand`].
+ *
+ */
+uint64_t wyrand_64_condom(uint64_t *seed);
+
+/**
+ *
+ * Create a streamed hasher of the [`WyHash64Condom`] variant with `seed` and `secret`, which
+ * must be released with [`wyhash_64_condom_stream_finish`] or [`wyhash_64_condom_stream_free`]
+ *
+ * # Safety
+ *
+ * `secret` must be either null for the default secret or point to 4 readable words.
+ *
+ */
+WyHash64CondomStream *wyhash_64_condom_stream_new(uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * Feed `len` bytes at `key` to the streamed hasher
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_64_condom_stream_new`] and not yet released, and `key`
+ * must point to `len` readable bytes.
+ *
+ */
+void wyhash_64_condom_stream_update(WyHash64CondomStream *stream, const void *key, size_t len);
+
+/**
+ *
+ * Release the streamed hasher and return the hash of all the bytes fed to it
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_64_condom_stream_new`] and not yet released.
+ *
+ */
+uint64_t wyhash_64_condom_stream_finish(WyHash64CondomStream *stream);
+
+/**
+ *
+ * Release the streamed hasher without finishing it
+ *
+ * # Safety
+ *
+ * `stream` must be either null, or created by [`wyhash_64_condom_stream_new`] and not yet
+ * released.
+ *
+ */
+void wyhash_64_condom_stream_free(WyHash64CondomStream *stream);
+
+/**
+ *
+ * [`wyhash`] of the [`WyHash32`] variant
+ *
+ * # Safety
+ *
+ * See [`wyhash`].
+ *
+ */
+uint64_t wyhash_32(const void *key, size_t len, uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * [`make_secret`] of the [`WyHash32`] variant
+ *
+ * # Safety
+ *
+ * See [`make_secret`].
+ *
+ */
+void make_secret_32(uint64_t seed, uint64_t *secret);
+
+/**
+ *
+ * [`wyrand`] of the [`WyHash32`] variant
+ *
+ * # Safety
+ *
+ * See [`wyrand`].
+ *
+ */
+uint64_t wyrand_32(uint64_t *seed);
+
+/**
+ *
+ * Create a streamed hasher of the [`WyHash32`] variant with `seed` and `secret`, which must be
+ * released with [`wyhash_32_stream_finish`] or [`wyhash_32_stream_free`]
+ *
+ * # Safety
+ *
+ * `secret` must be either null for the default secret or point to 4 readable words.
+ *
+ */
+WyHash32Stream *wyhash_32_stream_new(uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * Feed `len` bytes at `key` to the streamed hasher
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_32_stream_new`] and not yet released, and `key` must
+ * point to `len` readable bytes.
+ *
+ */
+void wyhash_32_stream_update(WyHash32Stream *stream, const void *key, size_t len);
+
+/**
+ *
+ * Release the streamed hasher and return the hash of all the bytes fed to it
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_32_stream_new`] and not yet released.
+ *
+ */
+uint64_t wyhash_32_stream_finish(WyHash32Stream *stream);
+
+/**
+ *
+ * Release the streamed hasher without finishing it
+ *
+ * # Safety
+ *
+ * `stream` must be either null, or created by [`wyhash_32_stream_new`] and not yet released.
+ *
+ */
+void wyhash_32_stream_free(WyHash32Stream *stream);
+
+/**
+ *
+ * [`wyhash`] of the [`WyHash32Condom`] variant
+ *
+ * # Safety
+ *
+ * See [`wyhash`].
+ *
+ */
+uint64_t wyhash_32_condom(const void *key, size_t len, uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * [`make_secret`] of the [`WyHash32Condom`] variant
+ *
+ * # Safety
+ *
+ * See [`make_secret`].
+ *
+ */
+void make_secret_32_condom(uint64_t seed, uint64_t *secret);
+
+/**
+ *
+ * [`wyrand`] of the [`WyHash32Condom`] variant
+ *
+ * # Safety
+ *
+ * See [`wyrand`].
+ *
+ */
+uint64_t wyrand_32_condom(uint64_t *seed);
+
+/**
+ *
+ * Create a streamed hasher of the [`WyHash32Condom`] variant with `seed` and `secret`, which
+ * must be released with [`wyhash_32_condom_stream_finish`] or [`wyhash_32_condom_stream_free`]
+ *
+ * # Safety
+ *
+ * `secret` must be either null for the default secret or point to 4 readable words.
+ *
+ */
+WyHash32CondomStream *wyhash_32_condom_stream_new(uint64_t seed, const uint64_t *secret);
+
+/**
+ *
+ * Feed `len` bytes at `key` to the streamed hasher
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_32_condom_stream_new`] and not yet released, and `key`
+ * must point to `len` readable bytes.
+ *
+ */
+void wyhash_32_condom_stream_update(WyHash32CondomStream *stream, const void *key, size_t len);
+
+/**
+ *
+ * Release the streamed hasher and return the hash of all the bytes fed to it
+ *
+ * # Safety
+ *
+ * `stream` must be created by [`wyhash_32_condom_stream_new`] and not yet released.
+ *
+ */
+uint64_t wyhash_32_condom_stream_finish(WyHash32CondomStream *stream);
+
+/**
+ *
+ * Release the streamed hasher without finishing it
+ *
+ * # Safety
+ *
+ * `stream` must be either null, or created by [`wyhash_32_condom_stream_new`] and not yet
+ * released.
+ *
+ */
+void wyhash_32_condom_stream_free(WyHash32CondomStream *stream);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* WYHASH_FINAL4_H */
@@ -0,0 +1,639 @@
+//!
+//! The C ABI of `wyhash-final4`, compatible with the reference `wyhash.h`.
+//!
+//! `wyhash`, `make_secret` and `wyrand` have the same signatures and results as those of
+//! `wyhash.h` with the default settings, i.e. [`WyHash64`]. Each variant also has its own
+//! functions, suffixed with `_64`, `_64_condom`, `_32` and `_32_condom`:
+//!
+//! | Variant            | `wyhash.h` settings                          |
+//! |--------------------|----------------------------------------------|
+//! | [`WyHash64`]       | `WYHASH_CONDOM` 0 or 1, `WYHASH_32BIT_MUM` 0 |
+//! | [`WyHash64Condom`] | `WYHASH_CONDOM` 2, `WYHASH_32BIT_MUM` 0      |
+//! | [`WyHash32`]       | `WYHASH_CONDOM` 0 or 1, `WYHASH_32BIT_MUM` 1 |
+//! | [`WyHash32Condom`] | `WYHASH_CONDOM` 2, `WYHASH_32BIT_MUM` 1      |
+//!
+//! This crate builds these functions into a `cdylib` and a `staticlib`, declared in the header
+//! `include/wyhash_final4.h`, which is generated from this file with `cbindgen`.
+//!
+
+use core::ffi::c_void;
+
+use wyhash_final4::generics::{StreamedWyHasher, WyHashVariant, WyHasher};
+use wyhash_final4::wyrand::WyRand;
+use wyhash_final4::{WyHash32, WyHash32Condom, WyHash64, WyHash64Condom};
+
+/// A streamed hasher of [`WyHash64`]
+pub struct WyHash64Stream(StreamedWyHasher<WyHash64>);
+
+/// A streamed hasher of [`WyHash64Condom`]
+pub struct WyHash64CondomStream(StreamedWyHasher<WyHash64Condom>);
+
+/// A streamed hasher of [`WyHash32`]
+pub struct WyHash32Stream(StreamedWyHasher<WyHash32>);
+
+/// A streamed hasher of [`WyHash32Condom`]
+pub struct WyHash32CondomStream(StreamedWyHasher<WyHash32Condom>);
+
+unsafe fn hasher<V: WyHashVariant>(seed: u64, secret: *const u64) -> WyHasher<V> {
+    if secret.is_null() {
+        WyHasher::from_seed(seed)
+    } else {
+        WyHasher::new(seed, secret.cast::<[u64; 4]>().read_unaligned())
+    }
+}
+
+unsafe fn input<'a>(key: *const c_void, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(key.cast::<u8>(), len)
+    }
+}
+
+unsafe fn hash<V: WyHashVariant>(
+    key: *const c_void,
+    len: usize,
+    seed: u64,
+    secret: *const u64,
+) -> u64 {
+    hasher::<V>(seed, secret).hash(input(key, len))
+}
+
+unsafe fn write_secret<V: WyHashVariant>(seed: u64, secret: *mut u64) {
+    secret
+        .cast::<[u64; 4]>()
+        .write_unaligned(V::generate_secret(seed));
+}
+
+unsafe fn stream_new<V: WyHashVariant>(seed: u64, secret: *const u64) -> StreamedWyHasher<V> {
+    hasher(seed, secret).streamed()
+}
+
+unsafe fn next<V: WyHashVariant>(seed: *mut u64) -> u64 {
+    let mut rng = WyRand::<V>::from_seed(*seed);
+    let r = rng.next_u64();
+    *seed = rng.seed();
+    r
+}
+
+unsafe fn stream_update<V: WyHashVariant>(
+    stream: &mut StreamedWyHasher<V>,
+    key: *const c_void,
+    len: usize,
+) {
+    stream.write(input(key, len))
+}
+
+///
+/// Hash `len` bytes at `key` with `seed` and the 4 words of `secret`, like `wyhash()` of
+/// `wyhash.h` with the default settings
+///
+/// # Safety
+///
+/// `key` must point to `len` readable bytes, and `secret` must be either null for the default
+/// secret or point to 4 readable words.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash(
+    key: *const c_void,
+    len: usize,
+    seed: u64,
+    secret: *const u64,
+) -> u64 {
+    hash::<WyHash64>(key, len, seed, secret)
+}
+
+///
+/// Generate a secret from `seed` into the 4 words at `secret`, like `make_secret()` of
+/// `wyhash.h` with the default settings
+///
+/// # Safety
+///
+/// `secret` must point to 4 writable words.
+///
+#[no_mangle]
+pub unsafe extern "C" fn make_secret(seed: u64, secret: *mut u64) {
+    write_secret::<WyHash64>(seed, secret)
+}
+
+///
+/// Advance the state at `seed` and return a random number, like `wyrand()` of `wyhash.h` with
+/// the default settings
+///
+/// # Safety
+///
+/// `seed` must point to a readable and writable word.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyrand(seed: *mut u64) -> u64 {
+    next::<WyHash64>(seed)
+}
+
+///
+/// [`wyhash`] of the [`WyHash64`] variant
+///
+/// # Safety
+///
+/// See [`wyhash`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64(
+    key: *const c_void,
+    len: usize,
+    seed: u64,
+    secret: *const u64,
+) -> u64 {
+    hash::<WyHash64>(key, len, seed, secret)
+}
+
+///
+/// [`make_secret`] of the [`WyHash64`] variant
+///
+/// # Safety
+///
+/// See [`make_secret`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn make_secret_64(seed: u64, secret: *mut u64) {
+    write_secret::<WyHash64>(seed, secret)
+}
+
+///
+/// [`wyrand`] of the [`WyHash64`] variant
+///
+/// # Safety
+///
+/// See [`wyrand`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyrand_64(seed: *mut u64) -> u64 {
+    next::<WyHash64>(seed)
+}
+
+///
+/// Create a streamed hasher of the [`WyHash64`] variant with `seed` and `secret`, which must be
+/// released with [`wyhash_64_stream_finish`] or [`wyhash_64_stream_free`]
+///
+/// # Safety
+///
+/// `secret` must be either null for the default secret or point to 4 readable words.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_stream_new(
+    seed: u64,
+    secret: *const u64,
+) -> *mut WyHash64Stream {
+    Box::into_raw(Box::new(WyHash64Stream(stream_new(seed, secret))))
+}
+
+///
+/// Feed `len` bytes at `key` to the streamed hasher
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_64_stream_new`] and not yet released, and `key` must
+/// point to `len` readable bytes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_stream_update(
+    stream: *mut WyHash64Stream,
+    key: *const c_void,
+    len: usize,
+) {
+    stream_update(&mut (*stream).0, key, len)
+}
+
+///
+/// Release the streamed hasher and return the hash of all the bytes fed to it
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_64_stream_new`] and not yet released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_stream_finish(stream: *mut WyHash64Stream) -> u64 {
+    Box::from_raw(stream).0.finish()
+}
+
+///
+/// Release the streamed hasher without finishing it
+///
+/// # Safety
+///
+/// `stream` must be either null, or created by [`wyhash_64_stream_new`] and not yet released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_stream_free(stream: *mut WyHash64Stream) {
+    if !stream.is_null() {
+        drop(Box::from_raw(stream));
+    }
+}
+
+///
+/// [`wyhash`] of the [`WyHash64Condom`] variant
+///
+/// # Safety
+///
+/// See [`wyhash`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_condom(
+    key: *const c_void,
+    len: usize,
+    seed: u64,
+    secret: *const u64,
+) -> u64 {
+    hash::<WyHash64Condom>(key, len, seed, secret)
+}
+
+///
+/// [`make_secret`] of the [`WyHash64Condom`] variant
+///
+/// # Safety
+///
+/// See [`make_secret`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn make_secret_64_condom(seed: u64, secret: *mut u64) {
+    write_secret::<WyHash64Condom>(seed, secret)
+}
+
+///
+/// [`wyrand`] of the [`WyHash64Condom`] variant
+///
+/// # Safety
+///
+/// See [`wyrand`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyrand_64_condom(seed: *mut u64) -> u64 {
+    next::<WyHash64Condom>(seed)
+}
+
+///
+/// Create a streamed hasher of the [`WyHash64Condom`] variant with `seed` and `secret`, which
+/// must be released with [`wyhash_64_condom_stream_finish`] or [`wyhash_64_condom_stream_free`]
+///
+/// # Safety
+///
+/// `secret` must be either null for the default secret or point to 4 readable words.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_condom_stream_new(
+    seed: u64,
+    secret: *const u64,
+) -> *mut WyHash64CondomStream {
+    Box::into_raw(Box::new(WyHash64CondomStream(stream_new(seed, secret))))
+}
+
+///
+/// Feed `len` bytes at `key` to the streamed hasher
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_64_condom_stream_new`] and not yet released, and `key`
+/// must point to `len` readable bytes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_condom_stream_update(
+    stream: *mut WyHash64CondomStream,
+    key: *const c_void,
+    len: usize,
+) {
+    stream_update(&mut (*stream).0, key, len)
+}
+
+///
+/// Release the streamed hasher and return the hash of all the bytes fed to it
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_64_condom_stream_new`] and not yet released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_condom_stream_finish(stream: *mut WyHash64CondomStream) -> u64 {
+    Box::from_raw(stream).0.finish()
+}
+
+///
+/// Release the streamed hasher without finishing it
+///
+/// # Safety
+///
+/// `stream` must be either null, or created by [`wyhash_64_condom_stream_new`] and not yet
+/// released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_64_condom_stream_free(stream: *mut WyHash64CondomStream) {
+    if !stream.is_null() {
+        drop(Box::from_raw(stream));
+    }
+}
+
+///
+/// [`wyhash`] of the [`WyHash32`] variant
+///
+/// # Safety
+///
+/// See [`wyhash`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32(
+    key: *const c_void,
+    len: usize,
+    seed: u64,
+    secret: *const u64,
+) -> u64 {
+    hash::<WyHash32>(key, len, seed, secret)
+}
+
+///
+/// [`make_secret`] of the [`WyHash32`] variant
+///
+/// # Safety
+///
+/// See [`make_secret`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn make_secret_32(seed: u64, secret: *mut u64) {
+    write_secret::<WyHash32>(seed, secret)
+}
+
+///
+/// [`wyrand`] of the [`WyHash32`] variant
+///
+/// # Safety
+///
+/// See [`wyrand`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyrand_32(seed: *mut u64) -> u64 {
+    next::<WyHash32>(seed)
+}
+
+///
+/// Create a streamed hasher of the [`WyHash32`] variant with `seed` and `secret`, which must be
+/// released with [`wyhash_32_stream_finish`] or [`wyhash_32_stream_free`]
+///
+/// # Safety
+///
+/// `secret` must be either null for the default secret or point to 4 readable words.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_stream_new(
+    seed: u64,
+    secret: *const u64,
+) -> *mut WyHash32Stream {
+    Box::into_raw(Box::new(WyHash32Stream(stream_new(seed, secret))))
+}
+
+///
+/// Feed `len` bytes at `key` to the streamed hasher
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_32_stream_new`] and not yet released, and `key` must
+/// point to `len` readable bytes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_stream_update(
+    stream: *mut WyHash32Stream,
+    key: *const c_void,
+    len: usize,
+) {
+    stream_update(&mut (*stream).0, key, len)
+}
+
+///
+/// Release the streamed hasher and return the hash of all the bytes fed to it
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_32_stream_new`] and not yet released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_stream_finish(stream: *mut WyHash32Stream) -> u64 {
+    Box::from_raw(stream).0.finish()
+}
+
+///
+/// Release the streamed hasher without finishing it
+///
+/// # Safety
+///
+/// `stream` must be either null, or created by [`wyhash_32_stream_new`] and not yet released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_stream_free(stream: *mut WyHash32Stream) {
+    if !stream.is_null() {
+        drop(Box::from_raw(stream));
+    }
+}
+
+///
+/// [`wyhash`] of the [`WyHash32Condom`] variant
+///
+/// # Safety
+///
+/// See [`wyhash`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_condom(
+    key: *const c_void,
+    len: usize,
+    seed: u64,
+    secret: *const u64,
+) -> u64 {
+    hash::<WyHash32Condom>(key, len, seed, secret)
+}
+
+///
+/// [`make_secret`] of the [`WyHash32Condom`] variant
+///
+/// # Safety
+///
+/// See [`make_secret`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn make_secret_32_condom(seed: u64, secret: *mut u64) {
+    write_secret::<WyHash32Condom>(seed, secret)
+}
+
+///
+/// [`wyrand`] of the [`WyHash32Condom`] variant
+///
+/// # Safety
+///
+/// See [`wyrand`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyrand_32_condom(seed: *mut u64) -> u64 {
+    next::<WyHash32Condom>(seed)
+}
+
+///
+/// Create a streamed hasher of the [`WyHash32Condom`] variant with `seed` and `secret`, which
+/// must be released with [`wyhash_32_condom_stream_finish`] or [`wyhash_32_condom_stream_free`]
+///
+/// # Safety
+///
+/// `secret` must be either null for the default secret or point to 4 readable words.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_condom_stream_new(
+    seed: u64,
+    secret: *const u64,
+) -> *mut WyHash32CondomStream {
+    Box::into_raw(Box::new(WyHash32CondomStream(stream_new(seed, secret))))
+}
+
+///
+/// Feed `len` bytes at `key` to the streamed hasher
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_32_condom_stream_new`] and not yet released, and `key`
+/// must point to `len` readable bytes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_condom_stream_update(
+    stream: *mut WyHash32CondomStream,
+    key: *const c_void,
+    len: usize,
+) {
+    stream_update(&mut (*stream).0, key, len)
+}
+
+///
+/// Release the streamed hasher and return the hash of all the bytes fed to it
+///
+/// # Safety
+///
+/// `stream` must be created by [`wyhash_32_condom_stream_new`] and not yet released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_condom_stream_finish(stream: *mut WyHash32CondomStream) -> u64 {
+    Box::from_raw(stream).0.finish()
+}
+
+///
+/// Release the streamed hasher without finishing it
+///
+/// # Safety
+///
+/// `stream` must be either null, or created by [`wyhash_32_condom_stream_new`] and not yet
+/// released.
+///
+#[no_mangle]
+pub unsafe extern "C" fn wyhash_32_condom_stream_free(stream: *mut WyHash32CondomStream) {
+    if !stream.is_null() {
+        drop(Box::from_raw(stream));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wyhash_h_compatible() {
+        let key = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        let mut secret = [0u64; 4];
+        unsafe {
+            make_secret(42, secret.as_mut_ptr());
+            assert_eq!(secret, WyHash64::generate_secret(42));
+            assert_eq!(
+                wyhash(key.as_ptr().cast(), key.len(), 7, secret.as_ptr()),
+                WyHash64::with_seed_and_secret(7, secret).hash(key)
+            );
+            assert_eq!(
+                wyhash(core::ptr::null(), 0, 7, core::ptr::null()),
+                WyHash64::with_seed(7).hash(b"")
+            );
+
+            let mut state = 0x1234;
+            let mut rng = WyRand::<WyHash64>::from_seed(0x1234);
+            for _ in 0..4 {
+                assert_eq!(wyrand(&mut state), rng.next_u64());
+            }
+            assert_eq!(state, rng.seed());
+        }
+    }
+
+    #[test]
+    fn test_variants() {
+        let key: Vec<u8> = (0..200u8).collect();
+        let seed = 99;
+        unsafe {
+            macro_rules! check {
+                ($v: ty, $hash: ident, $make_secret: ident, $wyrand: ident,
+                 $new: ident, $update: ident, $finish: ident, $free: ident) => {
+                    let mut secret = [0u64; 4];
+                    $make_secret(seed, secret.as_mut_ptr());
+                    assert_eq!(secret, <$v>::generate_secret(seed));
+
+                    let expected = <$v>::with_seed_and_secret(seed, secret).hash(&key);
+                    assert_eq!(
+                        $hash(key.as_ptr().cast(), key.len(), seed, secret.as_ptr()),
+                        expected
+                    );
+
+                    let stream = $new(seed, secret.as_ptr());
+                    for chunk in key.chunks(7) {
+                        $update(stream, chunk.as_ptr().cast(), chunk.len());
+                    }
+                    $update(stream, core::ptr::null(), 0);
+                    assert_eq!($finish(stream), expected);
+                    $free($new(seed, core::ptr::null()));
+                    $free(core::ptr::null_mut());
+
+                    let mut state = seed;
+                    let mut rng = WyRand::<$v>::from_seed(seed);
+                    assert_eq!($wyrand(&mut state), rng.next_u64());
+                    assert_eq!(state, rng.seed());
+                };
+            }
+
+            check!(
+                WyHash64,
+                wyhash_64,
+                make_secret_64,
+                wyrand_64,
+                wyhash_64_stream_new,
+                wyhash_64_stream_update,
+                wyhash_64_stream_finish,
+                wyhash_64_stream_free
+            );
+            check!(
+                WyHash64Condom,
+                wyhash_64_condom,
+                make_secret_64_condom,
+                wyrand_64_condom,
+                wyhash_64_condom_stream_new,
+                wyhash_64_condom_stream_update,
+                wyhash_64_condom_stream_finish,
+                wyhash_64_condom_stream_free
+            );
+            check!(
+                WyHash32,
+                wyhash_32,
+                make_secret_32,
+                wyrand_32,
+                wyhash_32_stream_new,
+                wyhash_32_stream_update,
+                wyhash_32_stream_finish,
+                wyhash_32_stream_free
+            );
+            check!(
+                WyHash32Condom,
+                wyhash_32_condom,
+                make_secret_32_condom,
+                wyrand_32_condom,
+                wyhash_32_condom_stream_new,
+                wyhash_32_condom_stream_update,
+                wyhash_32_condom_stream_finish,
+                wyhash_32_condom_stream_free
+            );
+        }
+    }
+}
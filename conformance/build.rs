@@ -0,0 +1,24 @@
+///
+/// The settings of `wyhash.h` corresponding to each variant, as (prefix, `WYHASH_CONDOM`,
+/// `WYHASH_32BIT_MUM`)
+///
+const VARIANTS: [(&str, &str, &str); 4] = [
+    ("wyref_64", "1", "0"),
+    ("wyref_64_condom", "2", "0"),
+    ("wyref_32", "1", "1"),
+    ("wyref_32_condom", "2", "1"),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=c/wyhash.h");
+    println!("cargo:rerun-if-changed=c/shim.c");
+
+    for (prefix, condom, mum32) in VARIANTS {
+        cc::Build::new()
+            .file("c/shim.c")
+            .define("WYREF_PREFIX", prefix)
+            .define("WYHASH_CONDOM", condom)
+            .define("WYHASH_32BIT_MUM", mum32)
+            .compile(prefix);
+    }
+}
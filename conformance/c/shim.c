@@ -0,0 +1,50 @@
+/*
+ * Exports the functions of wyhash.h with a prefix, so that it can be compiled under different
+ * settings into the same binary. `WYREF_PREFIX` is defined by the build script.
+ */
+
+#include <stddef.h>
+#include "wyhash.h"
+
+#define WYREF_CONCAT_(a, b) a##_##b
+#define WYREF_CONCAT(a, b) WYREF_CONCAT_(a, b)
+#define WYREF(name) WYREF_CONCAT(WYREF_PREFIX, name)
+
+/*
+ * The default secret of wyhash-final4. It's passed explicitly instead of `_wyp`, as the default
+ * secret of wyhash.h changed between revisions of final4.
+ */
+static const uint64_t WYREF(default_secret)[4] = {
+    0xa0761d6478bd642full, 0xe7037ed1a0b428dbull, 0x8ebc6af09c88c6e3ull, 0x589965cc75374cc3ull,
+};
+
+uint64_t WYREF(wyhash)(const void *key, size_t len, uint64_t seed, const uint64_t *secret) {
+    return wyhash(key, len, seed, secret == NULL ? WYREF(default_secret) : secret);
+}
+
+void WYREF(make_secret)(uint64_t seed, uint64_t *secret) {
+    make_secret(seed, secret);
+}
+
+uint64_t WYREF(wyrand)(uint64_t *seed) {
+    return wyrand(seed);
+}
+
+uint64_t WYREF(wyhash64)(uint64_t a, uint64_t b) {
+    return wyhash64(a, b);
+}
+
+double WYREF(wy2u01)(uint64_t r) {
+    return _wy2u01(r);
+}
+
+double WYREF(wy2gau)(uint64_t r) {
+    return _wy2gau(r);
+}
+
+/* wyhash.h only defines `_wy2u0k` with the 64-bit multiplication */
+#if !WYHASH_32BIT_MUM
+uint64_t WYREF(wy2u0k)(uint64_t r, uint64_t k) {
+    return _wy2u0k(r, k);
+}
+#endif
@@ -0,0 +1,249 @@
+//!
+//! Differential conformance tests of `wyhash-final4` against the reference C implementation.
+//!
+//! The build script compiles `c/wyhash.h` four times, under every combination of
+//! `WYHASH_CONDOM` and `WYHASH_32BIT_MUM`, and the tests compare the results of the reference
+//! with those of each variant on randomized inputs, seeds and secrets. See `c/UPSTREAM.md` for
+//! the origin of the header.
+//!
+
+use core::ffi::c_void;
+
+///
+/// The functions of `wyhash.h` compiled with the settings of one variant
+///
+pub struct Reference {
+    pub wyhash: unsafe extern "C" fn(*const c_void, usize, u64, *const u64) -> u64,
+    pub make_secret: unsafe extern "C" fn(u64, *mut u64),
+    pub wyrand: unsafe extern "C" fn(*mut u64) -> u64,
+    pub wyhash64: unsafe extern "C" fn(u64, u64) -> u64,
+    pub wy2u01: unsafe extern "C" fn(u64) -> f64,
+    pub wy2gau: unsafe extern "C" fn(u64) -> f64,
+    /// `_wy2u0k`, which `wyhash.h` doesn't define with `WYHASH_32BIT_MUM`
+    pub wy2u0k: Option<unsafe extern "C" fn(u64, u64) -> u64>,
+}
+
+impl Reference {
+    ///
+    /// Hash the input with given seed and secret, or the default secret of this crate if `None`
+    ///
+    pub fn hash(&self, input: &[u8], seed: u64, secret: Option<&[u64; 4]>) -> u64 {
+        let secret = secret.map_or(core::ptr::null(), |s| s.as_ptr());
+        unsafe { (self.wyhash)(input.as_ptr().cast(), input.len(), seed, secret) }
+    }
+
+    ///
+    /// Generate a secret with given seed
+    ///
+    pub fn make_secret(&self, seed: u64) -> [u64; 4] {
+        let mut secret = [0u64; 4];
+        unsafe { (self.make_secret)(seed, secret.as_mut_ptr()) };
+        secret
+    }
+
+    ///
+    /// Generate a random number and advance the state
+    ///
+    pub fn wyrand(&self, seed: &mut u64) -> u64 {
+        unsafe { (self.wyrand)(seed) }
+    }
+
+    ///
+    /// Hash two integers with `wyhash64`
+    ///
+    pub fn wyhash64(&self, a: u64, b: u64) -> u64 {
+        unsafe { (self.wyhash64)(a, b) }
+    }
+
+    ///
+    /// Convert a random number to a `f64` in `[0, 1)` with `_wy2u01`
+    ///
+    pub fn wy2u01(&self, r: u64) -> f64 {
+        unsafe { (self.wy2u01)(r) }
+    }
+
+    ///
+    /// Convert a random number to an approximately Gaussian `f64` with `_wy2gau`
+    ///
+    pub fn wy2gau(&self, r: u64) -> f64 {
+        unsafe { (self.wy2gau)(r) }
+    }
+
+    ///
+    /// Convert a random number to an integer in `[0, k)` with `_wy2u0k`, or `None` if it's not
+    /// defined under the settings of this variant
+    ///
+    pub fn wy2u0k(&self, r: u64, k: u64) -> Option<u64> {
+        self.wy2u0k.map(|wy2u0k| unsafe { wy2u0k(r, k) })
+    }
+}
+
+macro_rules! reference {
+    (@option) => {
+        None
+    };
+    (@option $f: ident) => {
+        Some($f)
+    };
+    ($name: ident, $wyhash: ident, $make_secret: ident, $wyrand: ident, $wyhash64: ident,
+     $wy2u01: ident, $wy2gau: ident $(, $wy2u0k: ident)?) => {
+        extern "C" {
+            fn $wyhash(key: *const c_void, len: usize, seed: u64, secret: *const u64) -> u64;
+            fn $make_secret(seed: u64, secret: *mut u64);
+            fn $wyrand(seed: *mut u64) -> u64;
+            fn $wyhash64(a: u64, b: u64) -> u64;
+            fn $wy2u01(r: u64) -> f64;
+            fn $wy2gau(r: u64) -> f64;
+            $(fn $wy2u0k(r: u64, k: u64) -> u64;)?
+        }
+
+        pub const $name: Reference = Reference {
+            wyhash: $wyhash,
+            make_secret: $make_secret,
+            wyrand: $wyrand,
+            wyhash64: $wyhash64,
+            wy2u01: $wy2u01,
+            wy2gau: $wy2gau,
+            wy2u0k: reference!(@option $($wy2u0k)?),
+        };
+    };
+}
+
+reference!(
+    WYHASH_64,
+    wyref_64_wyhash,
+    wyref_64_make_secret,
+    wyref_64_wyrand,
+    wyref_64_wyhash64,
+    wyref_64_wy2u01,
+    wyref_64_wy2gau,
+    wyref_64_wy2u0k
+);
+reference!(
+    WYHASH_64_CONDOM,
+    wyref_64_condom_wyhash,
+    wyref_64_condom_make_secret,
+    wyref_64_condom_wyrand,
+    wyref_64_condom_wyhash64,
+    wyref_64_condom_wy2u01,
+    wyref_64_condom_wy2gau,
+    wyref_64_condom_wy2u0k
+);
+reference!(
+    WYHASH_32,
+    wyref_32_wyhash,
+    wyref_32_make_secret,
+    wyref_32_wyrand,
+    wyref_32_wyhash64,
+    wyref_32_wy2u01,
+    wyref_32_wy2gau
+);
+reference!(
+    WYHASH_32_CONDOM,
+    wyref_32_condom_wyhash,
+    wyref_32_condom_make_secret,
+    wyref_32_condom_wyrand,
+    wyref_32_condom_wyhash64,
+    wyref_32_condom_wy2u01,
+    wyref_32_condom_wy2gau
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wyhash_final4::generics::{StreamedWyHasher, WyHashVariant, WyHasher};
+    use wyhash_final4::wyrand::{wy2gau, wy2u01, wy2u0k, WyRand};
+    use wyhash_final4::{WyHash32, WyHash32Condom, WyHash64, WyHash64Condom};
+
+    const ROUNDS: usize = 2000;
+
+    fn random_input(rng: &mut WyRand<WyHash64>) -> Vec<u8> {
+        // Most of the inputs are short, to cover every branch of the short input path, while
+        // the others span several 48-byte blocks.
+        let len = match rng.next_below(4) {
+            0 => rng.next_below(1024),
+            _ => rng.next_below(100),
+        } as usize;
+        let mut input = vec![0u8; len];
+        rng.fill_bytes(&mut input);
+        input
+    }
+
+    fn streamed<V: WyHashVariant>(
+        rng: &mut WyRand<WyHash64>,
+        mut hasher: StreamedWyHasher<V>,
+        mut input: &[u8],
+    ) -> u64 {
+        while !input.is_empty() {
+            let len = (rng.next_below(100) as usize).min(input.len());
+            hasher.write(&input[..len]);
+            input = &input[len..];
+        }
+        hasher.finish()
+    }
+
+    fn check<V: WyHashVariant>(reference: &Reference, rng_seed: u64) {
+        let mut rng = WyRand::<WyHash64>::from_seed(rng_seed);
+        for _ in 0..ROUNDS {
+            let input = random_input(&mut rng);
+            let seed = rng.next_u64();
+            let secret_seed = rng.next_u64();
+
+            let secret = reference.make_secret(secret_seed);
+            assert_eq!(
+                V::generate_secret(secret_seed),
+                secret,
+                "seed: {}",
+                secret_seed
+            );
+
+            let expected = reference.hash(&input, seed, None);
+            let hasher = WyHasher::<V>::from_seed(seed);
+            assert_eq!(hasher.hash(&input), expected, "len: {}", input.len());
+            assert_eq!(streamed(&mut rng, hasher.streamed(), &input), expected);
+
+            let expected = reference.hash(&input, seed, Some(&secret));
+            let hasher = WyHasher::<V>::new(seed, secret);
+            assert_eq!(hasher.hash(&input), expected, "len: {}", input.len());
+            assert_eq!(streamed(&mut rng, hasher.streamed(), &input), expected);
+
+            let mut state = seed;
+            let mut wyrand = WyRand::<V>::from_seed(seed);
+            for _ in 0..4 {
+                assert_eq!(wyrand.next_u64(), reference.wyrand(&mut state));
+            }
+            assert_eq!(wyrand.seed(), state);
+
+            let (a, b) = (rng.next_u64(), rng.next_u64());
+            assert_eq!(V::hash_u64_pair(a, b), reference.wyhash64(a, b));
+
+            let r = rng.next_u64();
+            assert_eq!(wy2u01(r).to_bits(), reference.wy2u01(r).to_bits());
+            assert_eq!(wy2gau(r).to_bits(), reference.wy2gau(r).to_bits());
+            let k = rng.next_u64() >> rng.next_below(64);
+            if let Some(expected) = reference.wy2u0k(r, k) {
+                assert_eq!(wy2u0k::<V>(r, k), expected, "r: {}, k: {}", r, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wyhash64() {
+        check::<WyHash64>(&WYHASH_64, 1);
+    }
+
+    #[test]
+    fn test_wyhash64condom() {
+        check::<WyHash64Condom>(&WYHASH_64_CONDOM, 2);
+    }
+
+    #[test]
+    fn test_wyhash32() {
+        check::<WyHash32>(&WYHASH_32, 3);
+    }
+
+    #[test]
+    fn test_wyhash32condom() {
+        check::<WyHash32Condom>(&WYHASH_32_CONDOM, 4);
+    }
+}
@@ -0,0 +1,40 @@
+//!
+//! Check the invariants of the secrets generated from arbitrary seeds for every variant: each
+//! word is odd, consists of bytes with 4 bits set, and differs from each other word in 32 bits.
+//! Also, the `const fn` version must generate the same secret.
+//!
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wyhash_final4::generics::WyHashVariant;
+use wyhash_final4::secret::Secret;
+use wyhash_final4::{WyHash32, WyHash32Condom, WyHash64, WyHash64Condom};
+
+fn check<V: WyHashVariant>(seed: u64, const_generate_secret: fn(u64) -> [u64; 4]) {
+    let secret = V::generate_secret(seed);
+    for (i, word) in secret.iter().enumerate() {
+        assert_eq!(word & 1, 1, "word {} is even: {:#x}", i, word);
+        for byte in word.to_le_bytes() {
+            assert_eq!(
+                byte.count_ones(),
+                4,
+                "invalid byte {:#x} in word {}",
+                byte,
+                i
+            );
+        }
+        for (j, other) in secret[..i].iter().enumerate() {
+            assert_eq!((word ^ other).count_ones(), 32, "words {} and {}", j, i);
+        }
+    }
+    assert_eq!(Secret::try_from(secret).map(<[u64; 4]>::from), Ok(secret));
+    assert_eq!(const_generate_secret(seed), secret);
+}
+
+fuzz_target!(|seed: u64| {
+    check::<WyHash64>(seed, WyHash64::const_generate_secret);
+    check::<WyHash64Condom>(seed, WyHash64Condom::const_generate_secret);
+    check::<WyHash32>(seed, WyHash32::const_generate_secret);
+    check::<WyHash32Condom>(seed, WyHash32Condom::const_generate_secret);
+});
@@ -0,0 +1,49 @@
+//!
+//! Feed arbitrary chunks to `StreamedWyHasher`, and check that `finish` equals the one-shot
+//! `WyHasher::hash` of all the bytes written so far, for every variant.
+//!
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use wyhash_final4::generics::{ExportableVariant, StreamedWyHasher, WyHasher};
+use wyhash_final4::{WyHash32, WyHash32Condom, WyHash64, WyHash64Condom};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    seed: u64,
+    secret_seed: Option<u64>,
+    chunks: Vec<Vec<u8>>,
+}
+
+fn check<V: ExportableVariant>(input: &Input) {
+    let hasher = match input.secret_seed {
+        Some(secret_seed) => WyHasher::<V>::new(input.seed, V::generate_secret(secret_seed)),
+        None => WyHasher::<V>::from_seed(input.seed),
+    };
+    let mut streamed = hasher.streamed();
+    let mut written = Vec::new();
+    for chunk in &input.chunks {
+        streamed.write(chunk);
+        written.extend_from_slice(chunk);
+        assert_eq!(
+            streamed.finish(),
+            hasher.hash(&written),
+            "len: {}",
+            written.len()
+        );
+
+        // The exported state must resume to the same result
+        let resumed = StreamedWyHasher::<V>::from_state_bytes(&streamed.to_state_bytes()).unwrap();
+        assert_eq!(resumed.finish(), streamed.finish());
+    }
+    assert_eq!(streamed.finish(), hasher.hash(&written));
+}
+
+fuzz_target!(|input: Input| {
+    check::<WyHash64>(&input);
+    check::<WyHash64Condom>(&input);
+    check::<WyHash32>(&input);
+    check::<WyHash32Condom>(&input);
+});
@@ -0,0 +1,498 @@
+//!
+//! Print or check the wyhash of files, like `sha256sum` does.
+//!
+//! The output is made of `hash  path` lines as `sha256sum` prints. With `--manifest`, it starts
+//! with a header line recording the variant and seed, e.g.
+//! `# wyhashsum variant=WyHash64 seed=0x0000000000000000`, so that it can be verified later
+//! with `--check` without passing them again. The secret is never recorded, and must be passed
+//! again when checking.
+//!
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::ExitCode;
+
+use wyhash_final4::dynamic::{DynWyHasher, Variant};
+
+const USAGE: &str = "\
+Usage: wyhashsum [OPTION]... [FILE]...
+Print or check the wyhash of each FILE. With no FILE, or when FILE is -, read standard input.
+
+Options:
+  -c, --check           read the hashes from the FILEs and check them; the variant and seed
+                        are taken from the header of each FILE if present
+  -m, --manifest        start the output with a header recording the variant and seed
+  -a, --variant <NAME>  WyHash64 (default), WyHash64Condom, WyHash32 or WyHash32Condom
+  -s, --seed <SEED>     the seed, in decimal or 0x-prefixed hexadecimal (default 0)
+  -k, --secret <HEX>    the secret as 64 hexadecimal digits, i.e. the four 64-bit words in
+                        order, each written as 16 digits (default: the default secret)
+  -h, --help            print this help and exit
+";
+
+const HEADER_PREFIX: &str = "# wyhashsum";
+
+struct Options {
+    variant: Variant,
+    seed: u64,
+    secret: Option<[u64; 4]>,
+    check: bool,
+    manifest: bool,
+    files: Vec<String>,
+}
+
+impl Options {
+    fn hasher(&self, variant: Variant, seed: u64) -> DynWyHasher {
+        match self.secret {
+            Some(secret) => DynWyHasher::new(variant, seed, secret),
+            None => DynWyHasher::with_seed(variant, seed),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CheckSummary {
+    failed: usize,
+    missing: usize,
+    unreadable: usize,
+    malformed: usize,
+}
+
+impl CheckSummary {
+    fn success(&self) -> bool {
+        *self == CheckSummary::default()
+    }
+}
+
+fn parse_seed(s: &str) -> Result<u64, String> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    parsed.map_err(|_| format!("invalid seed '{}'", s))
+}
+
+fn parse_secret(s: &str) -> Result<[u64; 4], String> {
+    let hex = s.strip_prefix("0x").unwrap_or(s);
+    if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(format!(
+            "invalid secret '{}', expect 64 hexadecimal digits",
+            s
+        ));
+    }
+    let mut secret = [0u64; 4];
+    for (i, word) in secret.iter_mut().enumerate() {
+        *word = u64::from_str_radix(&hex[i * 16..(i + 1) * 16], 16).unwrap();
+    }
+    Ok(secret)
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        variant: Variant::WyHash64,
+        seed: 0,
+        secret: None,
+        check: false,
+        manifest: false,
+        files: Vec::new(),
+    };
+    let mut args = args.into_iter();
+    let mut only_files = false;
+    while let Some(arg) = args.next() {
+        if only_files || arg == "-" || !arg.starts_with('-') {
+            options.files.push(arg);
+            continue;
+        }
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("option '{}' requires a value", name))
+        };
+        match name {
+            "--" => only_files = true,
+            "-h" | "--help" => return Ok(None),
+            "-c" | "--check" => options.check = true,
+            "-m" | "--manifest" => options.manifest = true,
+            "-a" | "--variant" => {
+                let value = value()?;
+                options.variant = value
+                    .parse()
+                    .map_err(|_| format!("unknown variant '{}'", value))?;
+            }
+            "-s" | "--seed" => options.seed = parse_seed(&value()?)?,
+            "-k" | "--secret" => options.secret = Some(parse_secret(&value()?)?),
+            _ => return Err(format!("unknown option '{}'", name)),
+        }
+    }
+    if options.files.is_empty() {
+        options.files.push("-".to_string());
+    }
+    Ok(Some(options))
+}
+
+fn format_header(variant: Variant, seed: u64) -> String {
+    format!("{} variant={} seed={:#018x}", HEADER_PREFIX, variant, seed)
+}
+
+///
+/// Parse the variant and seed from a header line, or return `None` if the line isn't a header
+///
+fn parse_header(line: &str) -> Option<Result<(Variant, u64), String>> {
+    let fields = line.strip_prefix(HEADER_PREFIX)?;
+    if !fields.is_empty() && !fields.starts_with(' ') {
+        return None;
+    }
+    let mut variant = None;
+    let mut seed = None;
+    for field in fields.split_whitespace() {
+        match field.split_once('=') {
+            Some(("variant", value)) => match value.parse() {
+                Ok(value) => variant = Some(value),
+                Err(_) => return Some(Err(format!("unknown variant '{}'", value))),
+            },
+            Some(("seed", value)) => match parse_seed(value) {
+                Ok(value) => seed = Some(value),
+                Err(err) => return Some(Err(err)),
+            },
+            _ => return Some(Err(format!("invalid header field '{}'", field))),
+        }
+    }
+    match (variant, seed) {
+        (Some(variant), Some(seed)) => Some(Ok((variant, seed))),
+        _ => Some(Err("header must contain both variant and seed".to_string())),
+    }
+}
+
+///
+/// Parse a `hash  path` line
+///
+fn parse_line(line: &str) -> Option<(u64, &str)> {
+    let (hash, path) = line.split_once("  ")?;
+    if hash.len() != 16 || path.is_empty() {
+        return None;
+    }
+    Some((u64::from_str_radix(hash, 16).ok()?, path))
+}
+
+fn hash_file(hasher: &DynWyHasher, file: &str) -> io::Result<u64> {
+    if file == "-" {
+        hasher.hash_reader(io::stdin().lock())
+    } else {
+        hasher.hash_reader(BufReader::new(File::open(file)?))
+    }
+}
+
+///
+/// Print the hash of each file, preceded by a header if a manifest is requested
+///
+fn print_hashes<W: Write>(options: &Options, out: &mut W) -> io::Result<bool> {
+    let hasher = options.hasher(options.variant, options.seed);
+    let mut success = true;
+    if options.manifest {
+        writeln!(out, "{}", format_header(options.variant, options.seed))?;
+    }
+    for file in &options.files {
+        match hash_file(&hasher, file) {
+            Ok(hash) => writeln!(out, "{:016x}  {}", hash, file)?,
+            Err(err) => {
+                eprintln!("wyhashsum: {}: {}", file, err);
+                success = false;
+            }
+        }
+    }
+    out.flush()?;
+    Ok(success)
+}
+
+///
+/// Check the hashes listed in `manifest`, where lines before any header are checked with the
+/// variant and seed given by `options`
+///
+fn check_manifest<R: BufRead, W: Write>(
+    options: &Options,
+    name: &str,
+    manifest: R,
+    out: &mut W,
+) -> io::Result<CheckSummary> {
+    let mut summary = CheckSummary::default();
+    let mut hasher = options.hasher(options.variant, options.seed);
+    for (index, line) in manifest.lines().enumerate() {
+        let line = line?;
+        let line = line.trim_end_matches('\r');
+        if let Some(header) = parse_header(line) {
+            match header {
+                Ok((variant, seed)) => hasher = options.hasher(variant, seed),
+                Err(err) => {
+                    eprintln!("wyhashsum: {}:{}: {}", name, index + 1, err);
+                    summary.malformed += 1;
+                }
+            }
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((expected, path)) = parse_line(line) else {
+            eprintln!(
+                "wyhashsum: {}:{}: improperly formatted line",
+                name,
+                index + 1
+            );
+            summary.malformed += 1;
+            continue;
+        };
+        match hash_file(&hasher, path) {
+            Ok(hash) if hash == expected => writeln!(out, "{}: OK", path)?,
+            Ok(_) => {
+                writeln!(out, "{}: FAILED", path)?;
+                summary.failed += 1;
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                writeln!(out, "{}: missing", path)?;
+                summary.missing += 1;
+            }
+            Err(err) => {
+                writeln!(out, "{}: FAILED open or read", path)?;
+                eprintln!("wyhashsum: {}: {}", path, err);
+                summary.unreadable += 1;
+            }
+        }
+    }
+    Ok(summary)
+}
+
+fn check(options: &Options) -> io::Result<bool> {
+    let mut stdout = io::stdout().lock();
+    let mut success = true;
+    for file in &options.files {
+        let summary = if file == "-" {
+            check_manifest(options, file, io::stdin().lock(), &mut stdout)
+        } else {
+            File::open(file)
+                .and_then(|f| check_manifest(options, file, BufReader::new(f), &mut stdout))
+        };
+        let summary = match summary {
+            Ok(summary) => summary,
+            Err(err) => {
+                eprintln!("wyhashsum: {}: {}", file, err);
+                success = false;
+                continue;
+            }
+        };
+        let warnings = [
+            (
+                summary.malformed,
+                "line is improperly formatted",
+                "lines are improperly formatted",
+            ),
+            (
+                summary.unreadable,
+                "listed file could not be read",
+                "listed files could not be read",
+            ),
+            (
+                summary.missing,
+                "listed file is missing",
+                "listed files are missing",
+            ),
+            (
+                summary.failed,
+                "computed hash did NOT match",
+                "computed hashes did NOT match",
+            ),
+        ];
+        for (count, singular, plural) in warnings {
+            if count > 0 {
+                let message = if count == 1 { singular } else { plural };
+                eprintln!("wyhashsum: WARNING: {} {}", count, message);
+            }
+        }
+        success &= summary.success();
+    }
+    stdout.flush()?;
+    Ok(success)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("wyhashsum: {}\n\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let result = if options.check {
+        check(&options)
+    } else {
+        print_hashes(&options, &mut io::stdout().lock())
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("wyhashsum: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Option<Options>, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = args(&[]).unwrap().unwrap();
+        assert_eq!(options.variant, Variant::WyHash64);
+        assert_eq!(options.seed, 0);
+        assert_eq!(options.secret, None);
+        assert!(!options.check);
+        assert!(!options.manifest);
+        assert_eq!(options.files, ["-"]);
+
+        let options = args(&[
+            "-a",
+            "wyhash32condom",
+            "--seed=0x10",
+            "a",
+            "--secret",
+            "a0761d6478bd642fe7037ed1a0b428db8ebc6af09c88c6e3589965cc75374cc3",
+            "--",
+            "-b",
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(options.variant, Variant::WyHash32Condom);
+        assert_eq!(options.seed, 16);
+        assert_eq!(
+            options.secret,
+            Some([
+                0xa0761d6478bd642f,
+                0xe7037ed1a0b428db,
+                0x8ebc6af09c88c6e3,
+                0x589965cc75374cc3
+            ])
+        );
+        assert_eq!(options.files, ["a", "-b"]);
+        assert!(args(&["-c", "SUMS"]).unwrap().unwrap().check);
+        assert!(args(&["--manifest"]).unwrap().unwrap().manifest);
+
+        assert!(args(&["--help"]).unwrap().is_none());
+        assert!(args(&["--seed"]).is_err());
+        assert!(args(&["--seed", "x"]).is_err());
+        assert!(args(&["--secret", "1234"]).is_err());
+        assert!(args(&["--variant", "md5"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_header() {
+        let header = format_header(Variant::WyHash32Condom, 0x1234);
+        assert_eq!(
+            header,
+            "# wyhashsum variant=WyHash32Condom seed=0x0000000000001234"
+        );
+        assert_eq!(
+            parse_header(&header),
+            Some(Ok((Variant::WyHash32Condom, 0x1234)))
+        );
+        assert_eq!(parse_header("# comment"), None);
+        assert_eq!(parse_header("# wyhashsumx"), None);
+        assert!(matches!(parse_header("# wyhashsum seed=1"), Some(Err(_))));
+        assert!(matches!(
+            parse_header("# wyhashsum variant=md5 seed=1"),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_print_hashes() {
+        let path = std::env::temp_dir().join(format!("wyhashsum-print-{}", std::process::id()));
+        std::fs::write(&path, b"abc").unwrap();
+        let name = path.to_str().unwrap();
+        let line = format!(
+            "{:016x}  {}\n",
+            DynWyHasher::with_seed(Variant::WyHash32, 7).hash(b"abc"),
+            name
+        );
+
+        let mut out = Vec::new();
+        let options = args(&["-a", "WyHash32", "-s", "7", name]).unwrap().unwrap();
+        assert!(print_hashes(&options, &mut out).unwrap());
+        assert_eq!(String::from_utf8(out).unwrap(), line);
+
+        let mut out = Vec::new();
+        let options = args(&["-m", "-a", "WyHash32", "-s", "7", name])
+            .unwrap()
+            .unwrap();
+        assert!(print_hashes(&options, &mut out).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{}\n{}", format_header(Variant::WyHash32, 7), line)
+        );
+    }
+
+    #[test]
+    fn test_check_manifest() {
+        let dir = std::env::temp_dir();
+        let present = dir.join(format!("wyhashsum-check-{}", std::process::id()));
+        let missing = dir.join(format!("wyhashsum-missing-{}", std::process::id()));
+        std::fs::write(&present, b"abc").unwrap();
+        let present_name = present.to_str().unwrap();
+        let missing_name = missing.to_str().unwrap();
+
+        let options = args(&["-c"]).unwrap().unwrap();
+        let hash_64 = DynWyHasher::with_seed(Variant::WyHash64, 0).hash(b"abc");
+        let hash_32 = DynWyHasher::with_seed(Variant::WyHash32, 7).hash(b"abc");
+        let manifest = format!(
+            "{:016x}  {present}\n\
+             {header}\n\
+             {:016x}  {present}\n\
+             {:016x}  {present}\n\
+             {:016x}  {missing}\n\
+             not a hash line\n",
+            hash_64,
+            hash_32,
+            hash_64,
+            hash_32,
+            present = present_name,
+            missing = missing_name,
+            header = format_header(Variant::WyHash32, 7),
+        );
+        let mut out = Vec::new();
+        let summary = check_manifest(&options, "SUMS", manifest.as_bytes(), &mut out).unwrap();
+        std::fs::remove_file(&present).unwrap();
+
+        assert_eq!(
+            summary,
+            CheckSummary {
+                failed: 1,
+                missing: 1,
+                unreadable: 0,
+                malformed: 1,
+            }
+        );
+        assert!(!summary.success());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "{present}: OK\n{present}: OK\n{present}: FAILED\n{missing}: missing\n",
+                present = present_name,
+                missing = missing_name,
+            )
+        );
+    }
+}
@@ -0,0 +1,386 @@
+use crate::generics::{StreamedWyHasher, VariantTag, WyHashVariant, WyHasher};
+
+#[cfg(feature = "wyhash32")]
+use crate::WyHash32;
+#[cfg(feature = "wyhash32condom")]
+use crate::WyHash32Condom;
+#[cfg(feature = "wyhash64")]
+use crate::WyHash64;
+#[cfg(feature = "wyhash64condom")]
+use crate::WyHash64Condom;
+
+///
+/// The wyhash variants that can be selected at runtime, e.g. according to a file header.
+///
+/// A variant is printed as the name of its type, e.g. `WyHash64`, and can be parsed from its
+/// name case-insensitively.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    #[cfg(feature = "wyhash64")]
+    WyHash64,
+    #[cfg(feature = "wyhash64condom")]
+    WyHash64Condom,
+    #[cfg(feature = "wyhash32")]
+    WyHash32,
+    #[cfg(feature = "wyhash32condom")]
+    WyHash32Condom,
+}
+
+impl Variant {
+    ///
+    /// All the variants enabled in this build
+    ///
+    pub const ALL: &'static [Variant] = &[
+        #[cfg(feature = "wyhash64")]
+        Variant::WyHash64,
+        #[cfg(feature = "wyhash64condom")]
+        Variant::WyHash64Condom,
+        #[cfg(feature = "wyhash32")]
+        Variant::WyHash32,
+        #[cfg(feature = "wyhash32condom")]
+        Variant::WyHash32Condom,
+    ];
+
+    ///
+    /// The name of this variant, which is the name of its type
+    ///
+    pub const fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "wyhash64")]
+            Variant::WyHash64 => "WyHash64",
+            #[cfg(feature = "wyhash64condom")]
+            Variant::WyHash64Condom => "WyHash64Condom",
+            #[cfg(feature = "wyhash32")]
+            Variant::WyHash32 => "WyHash32",
+            #[cfg(feature = "wyhash32condom")]
+            Variant::WyHash32Condom => "WyHash32Condom",
+        }
+    }
+
+    ///
+    /// The tag of this variant in the exported state of a [`StreamedWyHasher`]
+    ///
+    pub const fn id(self) -> u8 {
+        match self {
+            #[cfg(feature = "wyhash64")]
+            Variant::WyHash64 => <WyHash64 as VariantTag>::ID,
+            #[cfg(feature = "wyhash64condom")]
+            Variant::WyHash64Condom => <WyHash64Condom as VariantTag>::ID,
+            #[cfg(feature = "wyhash32")]
+            Variant::WyHash32 => <WyHash32 as VariantTag>::ID,
+            #[cfg(feature = "wyhash32condom")]
+            Variant::WyHash32Condom => <WyHash32Condom as VariantTag>::ID,
+        }
+    }
+
+    ///
+    /// Get the variant with given tag, see [`Self::id`], if it's enabled in this build
+    ///
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.iter().copied().find(|variant| variant.id() == id)
+    }
+
+    ///
+    /// Generate a new secret with given seed, see [`WyHashVariant::generate_secret`]
+    ///
+    pub fn generate_secret(self, seed: u64) -> [u64; 4] {
+        match self {
+            #[cfg(feature = "wyhash64")]
+            Variant::WyHash64 => WyHash64::generate_secret(seed),
+            #[cfg(feature = "wyhash64condom")]
+            Variant::WyHash64Condom => WyHash64Condom::generate_secret(seed),
+            #[cfg(feature = "wyhash32")]
+            Variant::WyHash32 => WyHash32::generate_secret(seed),
+            #[cfg(feature = "wyhash32condom")]
+            Variant::WyHash32Condom => WyHash32Condom::generate_secret(seed),
+        }
+    }
+}
+
+impl core::fmt::Display for Variant {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl core::str::FromStr for Variant {
+    type Err = ParseVariantError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|variant| variant.name().eq_ignore_ascii_case(s))
+            .ok_or(ParseVariantError)
+    }
+}
+
+///
+/// The error returned when parsing an unknown or disabled [`Variant`]
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseVariantError;
+
+impl core::fmt::Display for ParseVariantError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown or disabled wyhash variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVariantError {}
+
+macro_rules! dispatch {
+    ($ty: ident, $value: expr, $inner: ident => $body: expr) => {
+        match $value {
+            #[cfg(feature = "wyhash64")]
+            $ty::WyHash64($inner) => $body,
+            #[cfg(feature = "wyhash64condom")]
+            $ty::WyHash64Condom($inner) => $body,
+            #[cfg(feature = "wyhash32")]
+            $ty::WyHash32($inner) => $body,
+            #[cfg(feature = "wyhash32condom")]
+            $ty::WyHash32Condom($inner) => $body,
+        }
+    };
+}
+
+macro_rules! construct {
+    ($ty: ident, $variant: expr, $generic: ident => $body: expr) => {
+        match $variant {
+            #[cfg(feature = "wyhash64")]
+            Variant::WyHash64 => {
+                type $generic = WyHash64;
+                $ty::WyHash64($body)
+            }
+            #[cfg(feature = "wyhash64condom")]
+            Variant::WyHash64Condom => {
+                type $generic = WyHash64Condom;
+                $ty::WyHash64Condom($body)
+            }
+            #[cfg(feature = "wyhash32")]
+            Variant::WyHash32 => {
+                type $generic = WyHash32;
+                $ty::WyHash32($body)
+            }
+            #[cfg(feature = "wyhash32condom")]
+            Variant::WyHash32Condom => {
+                type $generic = WyHash32Condom;
+                $ty::WyHash32Condom($body)
+            }
+        }
+    };
+}
+
+///
+/// A [`WyHasher`] whose variant is selected at runtime
+///
+#[derive(Clone)]
+pub enum DynWyHasher {
+    #[cfg(feature = "wyhash64")]
+    WyHash64(WyHasher<WyHash64>),
+    #[cfg(feature = "wyhash64condom")]
+    WyHash64Condom(WyHasher<WyHash64Condom>),
+    #[cfg(feature = "wyhash32")]
+    WyHash32(WyHasher<WyHash32>),
+    #[cfg(feature = "wyhash32condom")]
+    WyHash32Condom(WyHasher<WyHash32Condom>),
+}
+
+impl DynWyHasher {
+    ///
+    /// Create a new [`DynWyHasher`] instance of given variant with given seed and secret
+    ///
+    pub fn new<S: Into<[u64; 4]>>(variant: Variant, seed: u64, secret: S) -> Self {
+        let secret = secret.into();
+        construct!(DynWyHasher, variant, V => WyHasher::<V>::new(seed, secret))
+    }
+
+    ///
+    /// Create a new [`DynWyHasher`] instance of given variant with given seed and default secret
+    ///
+    pub fn with_seed(variant: Variant, seed: u64) -> Self {
+        construct!(DynWyHasher, variant, V => WyHasher::<V>::from_seed(seed))
+    }
+
+    ///
+    /// The variant of this hasher
+    ///
+    pub fn variant(&self) -> Variant {
+        match self {
+            #[cfg(feature = "wyhash64")]
+            DynWyHasher::WyHash64(_) => Variant::WyHash64,
+            #[cfg(feature = "wyhash64condom")]
+            DynWyHasher::WyHash64Condom(_) => Variant::WyHash64Condom,
+            #[cfg(feature = "wyhash32")]
+            DynWyHasher::WyHash32(_) => Variant::WyHash32,
+            #[cfg(feature = "wyhash32condom")]
+            DynWyHasher::WyHash32Condom(_) => Variant::WyHash32Condom,
+        }
+    }
+
+    ///
+    /// Hash the input, see [`WyHasher::hash`]
+    ///
+    pub fn hash(&self, input: &[u8]) -> u64 {
+        dispatch!(DynWyHasher, self, h => h.hash(input))
+    }
+
+    ///
+    /// Create a new [`DynStreamedWyHasher`] instance with the same variant, seed and secret used
+    /// by this hasher instance
+    ///
+    pub fn streamed(&self) -> DynStreamedWyHasher {
+        match self {
+            #[cfg(feature = "wyhash64")]
+            DynWyHasher::WyHash64(h) => DynStreamedWyHasher::WyHash64(h.streamed()),
+            #[cfg(feature = "wyhash64condom")]
+            DynWyHasher::WyHash64Condom(h) => DynStreamedWyHasher::WyHash64Condom(h.streamed()),
+            #[cfg(feature = "wyhash32")]
+            DynWyHasher::WyHash32(h) => DynStreamedWyHasher::WyHash32(h.streamed()),
+            #[cfg(feature = "wyhash32condom")]
+            DynWyHasher::WyHash32Condom(h) => DynStreamedWyHasher::WyHash32Condom(h.streamed()),
+        }
+    }
+
+    ///
+    /// Hash all the bytes read from `reader` until EOF, see [`WyHasher::hash_reader`]
+    ///
+    #[cfg(feature = "std")]
+    pub fn hash_reader<R: std::io::Read>(&self, reader: R) -> std::io::Result<u64> {
+        dispatch!(DynWyHasher, self, h => h.hash_reader(reader))
+    }
+}
+
+///
+/// A [`StreamedWyHasher`] whose variant is selected at runtime
+///
+#[derive(Clone, Debug)]
+pub enum DynStreamedWyHasher {
+    #[cfg(feature = "wyhash64")]
+    WyHash64(StreamedWyHasher<WyHash64>),
+    #[cfg(feature = "wyhash64condom")]
+    WyHash64Condom(StreamedWyHasher<WyHash64Condom>),
+    #[cfg(feature = "wyhash32")]
+    WyHash32(StreamedWyHasher<WyHash32>),
+    #[cfg(feature = "wyhash32condom")]
+    WyHash32Condom(StreamedWyHasher<WyHash32Condom>),
+}
+
+impl DynStreamedWyHasher {
+    ///
+    /// Create a new [`DynStreamedWyHasher`] instance of given variant with given seed and secret
+    ///
+    pub fn new<S: Into<[u64; 4]>>(variant: Variant, seed: u64, secret: S) -> Self {
+        let secret = secret.into();
+        construct!(DynStreamedWyHasher, variant, V => StreamedWyHasher::<V>::new(seed, secret))
+    }
+
+    ///
+    /// The variant of this hasher
+    ///
+    pub fn variant(&self) -> Variant {
+        match self {
+            #[cfg(feature = "wyhash64")]
+            DynStreamedWyHasher::WyHash64(_) => Variant::WyHash64,
+            #[cfg(feature = "wyhash64condom")]
+            DynStreamedWyHasher::WyHash64Condom(_) => Variant::WyHash64Condom,
+            #[cfg(feature = "wyhash32")]
+            DynStreamedWyHasher::WyHash32(_) => Variant::WyHash32,
+            #[cfg(feature = "wyhash32condom")]
+            DynStreamedWyHasher::WyHash32Condom(_) => Variant::WyHash32Condom,
+        }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        dispatch!(DynStreamedWyHasher, self, h => h.write(bytes))
+    }
+
+    pub fn finish(&self) -> u64 {
+        dispatch!(DynStreamedWyHasher, self, h => h.finish())
+    }
+
+    ///
+    /// Reset this hasher to the initial state, see [`StreamedWyHasher::reset`]
+    ///
+    pub fn reset(&mut self) {
+        dispatch!(DynStreamedWyHasher, self, h => h.reset())
+    }
+}
+
+impl core::hash::Hasher for DynStreamedWyHasher {
+    fn finish(&self) -> u64 {
+        DynStreamedWyHasher::finish(self)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        DynStreamedWyHasher::write(self, bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for DynStreamedWyHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        DynStreamedWyHasher::write(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "std", feature = "wyhash64", feature = "wyhash32condom"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_variant_names() {
+        for variant in Variant::ALL {
+            assert_eq!(variant.to_string().parse::<Variant>(), Ok(*variant));
+            assert_eq!(Variant::from_id(variant.id()), Some(*variant));
+        }
+        assert_eq!(
+            "wyhash32condom".parse::<Variant>(),
+            Ok(Variant::WyHash32Condom)
+        );
+        assert_eq!("WyHash16".parse::<Variant>(), Err(ParseVariantError));
+    }
+
+    #[test]
+    fn test_dyn_hasher() {
+        let input = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        let secret = WyHash64::generate_secret(1);
+
+        let hasher = DynWyHasher::new(Variant::WyHash64, 7, secret);
+        assert_eq!(hasher.variant(), Variant::WyHash64);
+        assert_eq!(
+            hasher.hash(input),
+            WyHash64::with_seed_and_secret(7, secret).hash(input)
+        );
+
+        let hasher = DynWyHasher::with_seed(Variant::WyHash32Condom, 7);
+        assert_eq!(hasher.hash(input), WyHash32Condom::with_seed(7).hash(input));
+
+        let mut streamed = hasher.streamed();
+        assert_eq!(streamed.variant(), Variant::WyHash32Condom);
+        for chunk in input.chunks(5) {
+            streamed.write(chunk);
+        }
+        assert_eq!(streamed.finish(), hasher.hash(input));
+    }
+
+    #[test]
+    fn test_native_variant() {
+        use crate::generics::WyHashVariant;
+        use crate::{native_variant, NativeWyHash};
+
+        let input = b"wyhash";
+        assert_eq!(
+            DynWyHasher::with_seed(native_variant(), 0).hash(input),
+            NativeWyHash::hash(input)
+        );
+    }
+}
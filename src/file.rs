@@ -0,0 +1,125 @@
+//!
+//! Helpers for hashing files.
+//!
+//! Regular files are memory-mapped and hashed with the one-shot [`WyHasher::hash`] when the
+//! `mmap` feature is enabled, while pipes, character devices and other special files are read
+//! into a [`StreamedWyHasher`](crate::generics::StreamedWyHasher) through a buffer. Both paths
+//! give the same result as [`WyHasher::hash`] of the whole content of the file.
+//!
+//! The `mmap` feature is disabled by default, because a mapped file that is truncated by another
+//! process while being hashed makes reading the mapping undefined behaviour, which usually ends
+//! in a `SIGBUS` crash.
+//!
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use crate::generics::{WyHashVariant, WyHasher};
+
+///
+/// Hash the content of the file at `path` with default seed and secret
+///
+/// See [`hash_file_with`] for the requirements on concurrent modification of the file.
+///
+pub fn hash_file<V: WyHashVariant, P: AsRef<Path>>(path: P) -> io::Result<u64> {
+    hash_file_with(path, &V::with_default())
+}
+
+///
+/// Hash the content of the file at `path` with the seed and secret used by `hasher`
+///
+/// The file should not be modified while being hashed, otherwise the result is unspecified.
+///
+/// With the `mmap` feature, regular files are memory-mapped, and the file must not be truncated
+/// or modified by any process until this function returns. Otherwise the behaviour is undefined,
+/// and the process is likely to be killed by `SIGBUS`. Only enable the feature if the files
+/// hashed are not modified concurrently.
+///
+pub fn hash_file_with<V: WyHashVariant, P: AsRef<Path>>(
+    path: P,
+    hasher: &WyHasher<V>,
+) -> io::Result<u64> {
+    let file = File::open(path)?;
+
+    #[cfg(feature = "mmap")]
+    {
+        let metadata = file.metadata()?;
+        // Mapping an empty file fails on some platforms, and is of no use anyway.
+        if metadata.is_file() && metadata.len() > 0 {
+            // SAFETY: this is only sound if no process truncates or modifies the file while it's
+            // mapped, which can't be checked here. It's left to the user enabling `mmap`, see the
+            // documentation above.
+            if let Ok(map) = unsafe { memmap2::Mmap::map(&file) } {
+                return Ok(hasher.hash(&map));
+            }
+        }
+    }
+
+    hash_stream(file, hasher)
+}
+
+fn hash_stream<V: WyHashVariant>(file: File, hasher: &WyHasher<V>) -> io::Result<u64> {
+    hasher.hash_reader(io::BufReader::with_capacity(64 * 1024, file))
+}
+
+#[cfg(test)]
+#[cfg(feature = "wyhash64")]
+mod test {
+    use super::*;
+    use crate::WyHash64;
+    use std::path::PathBuf;
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, content: &[u8]) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("wyhash-final4-{}-{}", std::process::id(), name));
+            std::fs::write(&path, content).unwrap();
+            TempFile(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_hash_file() {
+        let hasher = WyHash64::with_seed(3);
+        for len in [0, 1, 16, 48, 100, 4096, 200_003] {
+            let content: Vec<u8> = (0..len).map(|i| (i * 7 + 1) as u8).collect();
+            let file = TempFile::new(&format!("hash-file-{}", len), &content);
+            let expected = hasher.hash(&content);
+            assert_eq!(hash_file_with(&file.0, &hasher).unwrap(), expected);
+            assert_eq!(
+                hash_stream(File::open(&file.0).unwrap(), &hasher).unwrap(),
+                expected
+            );
+            assert_eq!(
+                hash_file::<WyHash64, _>(&file.0).unwrap(),
+                WyHash64::hash(&content)
+            );
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hash_special_file() {
+        let hasher = WyHash64::with_seed(3);
+        assert_eq!(
+            hash_file_with("/dev/null", &hasher).unwrap(),
+            hasher.hash(b"")
+        );
+    }
+
+    #[test]
+    fn test_hash_file_error() {
+        let missing = std::env::temp_dir().join("wyhash-final4-missing-file");
+        let err = hash_file::<WyHash64, _>(missing).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
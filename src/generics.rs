@@ -1,15 +1,36 @@
 use crate::util::{likely, unlikely, wy_read_4, wy_read_8, wy_read_tail3, wy_read_tail8};
 use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 pub trait WyHashVariant: Clone + Sized {
+    ///
+    /// Multiply `a` and `b` and return the mixed low and high halves of the product, which is
+    /// `_wymum` of the reference implementation
+    ///
     fn mul_mum(a: u64, b: u64) -> (u64, u64);
 
+    ///
+    /// Multiply `a` and `b` and fold the product into a single `u64`, which is `_wymix` of the
+    /// reference implementation
+    ///
     #[inline(always)]
     fn mul_mix(a: u64, b: u64) -> u64 {
         let (a, b) = Self::mul_mum(a, b);
         a ^ b
     }
 
+    ///
+    /// Hash two integers, which is `wyhash64(A, B)` of the reference implementation
+    ///
+    /// This is the fast way to hash composite integer keys without building a byte slice, but
+    /// the result differs from hashing the bytes of the two integers.
+    ///
+    #[inline]
+    fn hash_u64_pair(a: u64, b: u64) -> u64 {
+        let (a, b) = Self::mul_mum(a ^ DEFAULT_SECRET[0], b ^ DEFAULT_SECRET[1]);
+        Self::mul_mix(a ^ DEFAULT_SECRET[0], b ^ DEFAULT_SECRET[1])
+    }
+
     ///
     /// Create a new [`WyHasher`] instance with default seed and secret
     ///
@@ -31,9 +52,10 @@ pub trait WyHashVariant: Clone + Sized {
     }
 
     ///
-    /// Create a new [`WyHasher`] instance with given seed and secret
+    /// Create a new [`WyHasher`] instance with given seed and secret, where the secret is
+    /// preferably a validated [`Secret`](crate::secret::Secret)
     ///
-    fn with_seed_and_secret(seed: u64, secret: [u64; 4]) -> WyHasher<Self>
+    fn with_seed_and_secret<S: Into<[u64; 4]>>(seed: u64, secret: S) -> WyHasher<Self>
     where
         Self: Sized,
     {
@@ -98,9 +120,19 @@ pub trait WyHashVariant: Clone + Sized {
     /// [`Self::with_seed_and_secret`], and hash the input with [`WyHasher::<V>::hash`], so that the initialization
     /// work can be reused.
     ///
-    fn hash_with_seed_and_secret(input: &[u8], seed: u64, secret: [u64; 4]) -> u64 {
+    fn hash_with_seed_and_secret<S: Into<[u64; 4]>>(input: &[u8], seed: u64, secret: S) -> u64 {
         Self::with_seed_and_secret(seed, secret).hash(input)
     }
+
+    ///
+    /// Hash all the bytes read from `reader` until EOF with default seed and secret
+    ///
+    /// The result is the same as [`Self::hash`] of all the bytes read.
+    ///
+    #[cfg(feature = "std")]
+    fn hash_reader<R: std::io::Read>(reader: R) -> std::io::Result<u64> {
+        Self::with_default().hash_reader(reader)
+    }
 }
 
 #[derive(Clone)]
@@ -112,9 +144,29 @@ pub struct WyHasher<T: WyHashVariant> {
 
 impl<T: WyHashVariant> WyHasher<T> {
     ///
-    /// Create a new [`WyHasher`] instance with given seed and secret
+    /// Create a [`WyHasher`] instance with the seed already mixed with the secret, for the
+    /// const constructors generated by [`impl_const_hash`]
     ///
-    pub fn new(mut seed: u64, secret: [u64; 4]) -> Self {
+    #[cfg(any(
+        feature = "wyhash32",
+        feature = "wyhash32condom",
+        feature = "wyhash64",
+        feature = "wyhash64condom"
+    ))]
+    pub(crate) const fn from_mixed_seed(seed: u64, secret: [u64; 4]) -> Self {
+        Self {
+            secret,
+            seed,
+            _marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Create a new [`WyHasher`] instance with given seed and secret, where the secret is
+    /// preferably a validated [`Secret`](crate::secret::Secret)
+    ///
+    pub fn new<S: Into<[u64; 4]>>(mut seed: u64, secret: S) -> Self {
+        let secret = secret.into();
         seed ^= T::mul_mix(seed ^ secret[0], secret[1]);
         Self {
             secret,
@@ -135,11 +187,35 @@ impl<T: WyHashVariant> WyHasher<T> {
         Self::new(seed, DEFAULT_SECRET)
     }
 
-    fn hash_short_input(&self, input: &[u8]) -> u64 {
+    ///
+    /// Create a [`BuildStreamedWyHasher`] with the same seed and secret used by this hasher
+    /// instance, whose hashers yield the same value as [`Self::hash`] for the concatenation
+    /// of all bytes written to them
+    ///
+    pub fn build_streamed(&self) -> BuildStreamedWyHasher<T> {
+        BuildStreamedWyHasher {
+            hasher: self.clone(),
+        }
+    }
+
+    ///
+    /// Hash all the bytes read from `reader` until EOF with the seed and secret used by this
+    /// hasher instance
+    ///
+    /// The result is the same as [`Self::hash`] of all the bytes read.
+    ///
+    #[cfg(feature = "std")]
+    pub fn hash_reader<R: std::io::Read>(&self, mut reader: R) -> std::io::Result<u64> {
+        let mut hasher = self.streamed();
+        std::io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finish())
+    }
+
+    #[inline(always)]
+    fn absorb_short_input(&self, input: &[u8]) -> (u64, u64, u64) {
         let len = input.len();
         let mut a = 0u64;
         let mut b = 0u64;
-        let seed = self.seed;
         unsafe {
             if len >= 4 {
                 let u = wy_read_4(input);
@@ -150,15 +226,99 @@ impl<T: WyHashVariant> WyHasher<T> {
                 a = wy_read_tail3(input);
             }
         }
-        self.epilogue(a, b, len, seed)
+        (a, b, self.seed)
+    }
+
+    pub fn hash(&self, input: &[u8]) -> u64 {
+        let (a, b, seed) = self.absorb(input);
+        self.epilogue(a, b, input.len(), seed)
     }
 
-    pub fn hash(&self, mut input: &[u8]) -> u64 {
+    ///
+    /// Hash each of `inputs` and store the results into `output`, which are identical to
+    /// hashing the inputs one by one with [`Self::hash`]
+    ///
+    /// The inputs are processed in groups of 4. When the inputs of a group are all at most 16
+    /// bytes, or all 17 to 48 bytes long, their independent multiplications are interleaved
+    /// step by step, which is faster than hashing them one by one. Other groups are hashed one
+    /// input after another.
+    ///
+    /// Panics if `inputs` and `output` have different lengths.
+    ///
+    pub fn hash_batch(&self, inputs: &[&[u8]], output: &mut [u64]) {
+        const LANES: usize = 4;
+
+        assert_eq!(
+            inputs.len(),
+            output.len(),
+            "inputs and output must have the same length"
+        );
+        let mut input_chunks = inputs.chunks_exact(LANES);
+        let mut output_chunks = output.chunks_exact_mut(LANES);
+        for (inputs, output) in (&mut input_chunks).zip(&mut output_chunks) {
+            let mut a = [0u64; LANES];
+            let mut b = [0u64; LANES];
+            let mut seed = [self.seed; LANES];
+            if inputs.iter().all(|input| input.len() <= 16) {
+                for l in 0..LANES {
+                    (a[l], b[l], _) = self.absorb_short_input(inputs[l]);
+                }
+            } else if inputs
+                .iter()
+                .all(|input| input.len() > 16 && input.len() <= 48)
+            {
+                // Each lane absorbs 16 bytes per round while more than 16 bytes are left, which
+                // is once or twice, and the last 16 bytes are read as the final words.
+                let mut p = [0usize; LANES];
+                for _ in 0..2 {
+                    for l in 0..LANES {
+                        let input = inputs[l];
+                        if input.len() - p[l] > 16 {
+                            unsafe {
+                                seed[l] = T::mul_mix(
+                                    wy_read_8(&input[p[l]..]) ^ self.secret[1],
+                                    wy_read_8(&input[p[l] + 8..]) ^ seed[l],
+                                );
+                            }
+                            p[l] += 16;
+                        }
+                    }
+                }
+                for l in 0..LANES {
+                    let input = inputs[l];
+                    unsafe {
+                        a[l] = wy_read_8(&input[input.len() - 16..]);
+                        b[l] = wy_read_8(&input[input.len() - 8..]);
+                    }
+                }
+            } else {
+                for l in 0..LANES {
+                    (a[l], b[l], seed[l]) = self.absorb(inputs[l]);
+                }
+            }
+            for l in 0..LANES {
+                output[l] = self.epilogue(a[l], b[l], inputs[l].len(), seed[l]);
+            }
+        }
+        for (out, input) in output_chunks
+            .into_remainder()
+            .iter_mut()
+            .zip(input_chunks.remainder())
+        {
+            *out = self.hash(input);
+        }
+    }
+
+    ///
+    /// Absorb the input into the state, and return the two words and seed to be mixed in
+    /// the epilogue
+    ///
+    #[inline(always)]
+    fn absorb(&self, mut input: &[u8]) -> (u64, u64, u64) {
         if likely(input.len() <= 16) {
-            return self.hash_short_input(input);
+            return self.absorb_short_input(input);
         }
 
-        let len = input.len();
         let mut a = 0u64;
         let mut b = 0u64;
         let mut seed = self.seed;
@@ -225,7 +385,7 @@ impl<T: WyHashVariant> WyHasher<T> {
                 b = b.wrapping_shr(shift) | wy_read_tail8(input).wrapping_shl(64 - shift);
             }
         }
-        self.epilogue(a, b, len, seed)
+        (a, b, seed)
     }
 
     fn epilogue(&self, mut a: u64, mut b: u64, len: usize, seed: u64) -> u64 {
@@ -242,8 +402,7 @@ impl<T: WyHashVariant> Default for WyHasher<T> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<T: WyHashVariant> std::hash::Hasher for WyHasher<T> {
+impl<T: WyHashVariant> core::hash::Hasher for WyHasher<T> {
     fn finish(&self) -> u64 {
         self.seed
     }
@@ -251,10 +410,45 @@ impl<T: WyHashVariant> std::hash::Hasher for WyHasher<T> {
     fn write(&mut self, bytes: &[u8]) {
         self.seed = self.hash(bytes);
     }
+
+    // Integers are folded into the state with a single `mul_mix` instead of going through
+    // `hash`, so the results differ from writing their byte representation. The word and the
+    // previous state share one multiplicand, so only a word depending on the keyed state can
+    // zero the product, rather than a fixed value like `secret[0]`.
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.seed = T::mul_mix(i ^ self.seed ^ self.secret[0], self.secret[1]);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
 }
 
-#[cfg(feature = "std")]
-impl<T: WyHashVariant> std::hash::BuildHasher for WyHasher<T> {
+impl<T: WyHashVariant> core::hash::BuildHasher for WyHasher<T> {
     type Hasher = Self;
 
     fn build_hasher(&self) -> Self::Hasher {
@@ -266,28 +460,130 @@ impl<T: WyHashVariant> std::hash::BuildHasher for WyHasher<T> {
     }
 }
 
+///
+/// A [`core::hash::BuildHasher`] that creates randomly keyed [`WyHasher`] instances, which is
+/// the equivalent of `std::collections::hash_map::RandomState` for wyhash.
+///
+/// The secret is generated with [`WyHashVariant::generate_secret`] from a random seed once per
+/// process, and the seed is drawn randomly for each instance. Randomness comes from the OS
+/// (through `std::collections::hash_map::RandomState`) and the system time when the `std`
+/// feature is enabled, otherwise from addresses and a global counter, in which case the secret
+/// is generated for each instance as well.
+///
+/// Without the `std` feature, the keys are only as unpredictable as the addresses. On bare-metal
+/// targets without address space layout randomization, the same keys are generated on every
+/// boot, so there is no protection against hash flooding there.
+///
+#[derive(Clone)]
+pub struct WyRandomState<V: WyHashVariant> {
+    hasher: WyHasher<V>,
+}
+
+impl<V: WyHashVariant> WyRandomState<V> {
+    ///
+    /// Create a new [`WyRandomState`] instance with a random seed
+    ///
+    pub fn new() -> Self {
+        Self {
+            hasher: WyHasher::new(random_seed::<V>(), process_secret::<V>()),
+        }
+    }
+}
+
+impl<V: WyHashVariant> Default for WyRandomState<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: WyHashVariant> core::fmt::Debug for WyRandomState<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WyRandomState").finish_non_exhaustive()
+    }
+}
+
+impl<V: WyHashVariant> core::hash::BuildHasher for WyRandomState<V> {
+    type Hasher = WyHasher<V>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        self.hasher.clone()
+    }
+}
+
+static RANDOM_STATE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn random_seed<V: WyHashVariant>() -> u64 {
+    let mut seed = entropy();
+    wyrand::<V>(&mut seed)
+}
+
+#[cfg(feature = "std")]
+fn entropy() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_usize(RANDOM_STATE_COUNTER.fetch_add(1, Ordering::Relaxed));
+    if let Ok(duration) = SystemTime::now().duration_since(UNIX_EPOCH) {
+        hasher.write_u128(duration.as_nanos());
+    }
+    hasher.finish()
+}
+
+#[cfg(not(feature = "std"))]
+fn entropy() -> u64 {
+    // Plain load and store, as some targets (e.g. thumbv6m) have no atomic read-modify-write.
+    // A lost increment only makes two instances share the counter value.
+    let count = RANDOM_STATE_COUNTER.load(Ordering::Relaxed);
+    RANDOM_STATE_COUNTER.store(count.wrapping_add(1), Ordering::Relaxed);
+    let local = 0u8;
+    (&local as *const u8 as usize as u64)
+        ^ (&RANDOM_STATE_COUNTER as *const AtomicUsize as usize as u64).rotate_left(32)
+        ^ (count as u64).rotate_left(16)
+}
+
+#[cfg(feature = "std")]
+fn process_secret<V: WyHashVariant>() -> [u64; 4] {
+    // The secret is shared by all variants, as its validity doesn't depend on the variant
+    static SECRET: std::sync::OnceLock<[u64; 4]> = std::sync::OnceLock::new();
+    *SECRET.get_or_init(|| V::generate_secret(random_seed::<V>()))
+}
+
+#[cfg(not(feature = "std"))]
+fn process_secret<V: WyHashVariant>() -> [u64; 4] {
+    V::generate_secret(random_seed::<V>())
+}
+
 // Used when generating secret
-const C: [u64; 70] = [
+pub(crate) const C: [u64; 70] = [
     15, 23, 27, 29, 30, 39, 43, 45, 46, 51, 53, 54, 57, 58, 60, 71, 75, 77, 78, 83, 85, 86, 89, 90,
     92, 99, 101, 102, 105, 106, 108, 113, 114, 116, 120, 135, 139, 141, 142, 147, 149, 150, 153,
     154, 156, 163, 165, 166, 169, 170, 172, 177, 178, 180, 184, 195, 197, 198, 201, 202, 204, 209,
     210, 212, 216, 225, 226, 228, 232, 240,
 ];
 
-const DEFAULT_SECRET: [u64; 4] = [
+pub(crate) const DEFAULT_SECRET: [u64; 4] = [
     0xa0761d6478bd642fu64,
     0xe7037ed1a0b428dbu64,
     0x8ebc6af09c88c6e3u64,
     0x589965cc75374cc3u64,
 ];
 
-fn wyrand<V: WyHashVariant>(seed: &mut u64) -> u64 {
+#[inline(always)]
+pub(crate) fn wyrand<V: WyHashVariant>(seed: &mut u64) -> u64 {
     *seed = seed.wrapping_add(0xa0761d6478bd642fu64);
     V::mul_mix(*seed, (*seed) ^ 0xe7037ed1a0b428dbu64)
 }
 
+#[derive(Clone)]
 pub struct StreamedWyHasher<T: WyHashVariant> {
+    // The seed right after initialization, used when resetting
+    initial_seed: u64,
     seed: u64,
+    // The two extra lanes used when hashing 48-byte blocks, they're folded into `seed`
+    // only when finishing, as the one-shot hash does
+    see1: u64,
+    see2: u64,
     secret: [u64; 4],
     buffer: [u8; 48],
     len: usize,
@@ -296,14 +592,22 @@ pub struct StreamedWyHasher<T: WyHashVariant> {
 }
 
 impl<T: WyHashVariant> StreamedWyHasher<T> {
-    pub fn new(mut seed: u64, secret: [u64; 4]) -> Self {
+    ///
+    /// Create a new [`StreamedWyHasher`] instance with given seed and secret, where the secret
+    /// is preferably a validated [`Secret`](crate::secret::Secret)
+    ///
+    pub fn new<S: Into<[u64; 4]>>(mut seed: u64, secret: S) -> Self {
+        let secret = secret.into();
         seed ^= T::mul_mix(seed ^ secret[0], secret[1]);
         Self::new_internal(seed, secret)
     }
 
     fn new_internal(seed: u64, secret: [u64; 4]) -> Self {
         Self {
+            initial_seed: seed,
             seed,
+            see1: seed,
+            see2: seed,
             secret,
             buffer: [0; 48],
             len: 0,
@@ -312,26 +616,38 @@ impl<T: WyHashVariant> StreamedWyHasher<T> {
         }
     }
 
-    fn process_buffer(&mut self) {
-        let mut seed = self.seed;
-        let mut s1 = self.seed;
-        let mut s2 = self.seed;
+    ///
+    /// Reset this hasher to the initial state, as if nothing has been written, while keeping
+    /// the seed and secret
+    ///
+    pub fn reset(&mut self) {
+        *self = Self::new_internal(self.initial_seed, self.secret);
+    }
+
+    ///
+    /// Get the hash of all the bytes written so far, and reset this hasher to the initial state
+    ///
+    pub fn finish_reset(&mut self) -> u64 {
+        let hash = self.finish();
+        self.reset();
+        hash
+    }
 
+    fn process_buffer(&mut self) {
         unsafe {
-            seed = T::mul_mix(
+            self.seed = T::mul_mix(
                 wy_read_8(&self.buffer[0..]) ^ self.secret[1],
-                wy_read_8(&self.buffer[8..]) ^ seed,
+                wy_read_8(&self.buffer[8..]) ^ self.seed,
             );
-            s1 = T::mul_mix(
+            self.see1 = T::mul_mix(
                 wy_read_8(&self.buffer[16..]) ^ self.secret[2],
-                wy_read_8(&self.buffer[24..]) ^ s1,
+                wy_read_8(&self.buffer[24..]) ^ self.see1,
             );
-            s2 = T::mul_mix(
+            self.see2 = T::mul_mix(
                 wy_read_8(&self.buffer[32..]) ^ self.secret[3],
-                wy_read_8(&self.buffer[40..]) ^ s2,
+                wy_read_8(&self.buffer[40..]) ^ self.see2,
             );
         }
-        self.seed = seed ^ s1 ^ s2;
     }
 
     pub fn finish(&self) -> u64 {
@@ -345,7 +661,7 @@ impl<T: WyHashVariant> StreamedWyHasher<T> {
         }
         let mut a;
         let mut b;
-        let mut seed = self.seed;
+        let mut seed = self.seed ^ self.see1 ^ self.see2;
         unsafe {
             if self.off > 32 {
                 seed = T::mul_mix(
@@ -396,10 +712,10 @@ impl<T: WyHashVariant> StreamedWyHasher<T> {
 
             if bytes.len() > 48 {
                 let mut seed = self.seed;
-                let mut s1 = self.seed;
-                let mut s2 = self.seed;
+                let mut s1 = self.see1;
+                let mut s2 = self.see2;
 
-                while bytes.len() >= 48 {
+                while bytes.len() > 48 {
                     unsafe {
                         seed = T::mul_mix(
                             wy_read_8(bytes) ^ self.secret[1],
@@ -421,7 +737,9 @@ impl<T: WyHashVariant> StreamedWyHasher<T> {
                     bytes = &bytes[48..];
                     self.len += 48;
                 }
-                self.seed = seed ^ s1 ^ s2;
+                self.seed = seed;
+                self.see1 = s1;
+                self.see2 = s2;
             }
         }
 
@@ -431,6 +749,418 @@ impl<T: WyHashVariant> StreamedWyHasher<T> {
     }
 }
 
+impl<T: WyHashVariant> Default for StreamedWyHasher<T> {
+    fn default() -> Self {
+        WyHasher::<T>::default().streamed()
+    }
+}
+
+impl<T: WyHashVariant> core::fmt::Debug for StreamedWyHasher<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamedWyHasher")
+            .field("len", &self.len)
+            .field("secret", &"<redacted>")
+            .finish_non_exhaustive()
+    }
+}
+
+///
+/// Size in bytes of the serialized state produced by [`StreamedWyHasher::to_state_bytes`]
+///
+pub const STREAMED_STATE_SIZE: usize = 123;
+
+// Version 1 was 115 bytes long, without the initial seed needed by `Hasher::finish`.
+const STREAMED_STATE_VERSION: u8 = 2;
+
+mod sealed {
+    ///
+    /// The tag identifying a built-in variant in the exported state of a
+    /// [`StreamedWyHasher`](super::StreamedWyHasher)
+    ///
+    /// It's sealed so that [`WyHashVariant`](super::WyHashVariant) can still be implemented
+    /// outside of this crate.
+    ///
+    pub trait VariantTag {
+        const ID: u8;
+    }
+}
+
+pub(crate) use sealed::VariantTag;
+
+///
+/// A variant of this crate, whose [`StreamedWyHasher`] state can be exported and restored
+///
+/// It's implemented for every built-in variant, and can be used as a bound by generic code
+/// calling [`StreamedWyHasher::to_state_bytes`], but can't be implemented outside of this crate.
+///
+pub trait ExportableVariant: WyHashVariant + VariantTag {}
+
+impl<T: WyHashVariant + VariantTag> ExportableVariant for T {}
+
+impl<T: ExportableVariant> StreamedWyHasher<T> {
+    ///
+    /// Export the internal state of this hasher, so that hashing can be resumed later,
+    /// possibly in another process, with [`Self::from_state_bytes`]
+    ///
+    /// Note that the state contains the secret used by this hasher. It's only available for the
+    /// variants of this crate, whose tags are recorded in the state.
+    ///
+    pub fn to_state_bytes(&self) -> [u8; STREAMED_STATE_SIZE] {
+        let mut state = [0u8; STREAMED_STATE_SIZE];
+        state[0] = STREAMED_STATE_VERSION;
+        state[1] = T::ID;
+        state[2..10].copy_from_slice(&self.seed.to_le_bytes());
+        state[10..18].copy_from_slice(&self.see1.to_le_bytes());
+        state[18..26].copy_from_slice(&self.see2.to_le_bytes());
+        for (i, word) in self.secret.iter().enumerate() {
+            state[(26 + i * 8)..(34 + i * 8)].copy_from_slice(&word.to_le_bytes());
+        }
+        state[58..66].copy_from_slice(&(self.len as u64).to_le_bytes());
+        state[66] = self.off as u8;
+        state[67..115].copy_from_slice(&self.buffer);
+        state[115..123].copy_from_slice(&self.initial_seed.to_le_bytes());
+        state
+    }
+
+    ///
+    /// Restore a hasher from the state exported by [`Self::to_state_bytes`]
+    ///
+    /// An error is returned if the state is malformed, of an unsupported version, or
+    /// exported by a hasher of another variant.
+    ///
+    pub fn from_state_bytes(state: &[u8]) -> Result<Self, StateError> {
+        // The version is checked first, as the length depends on it.
+        match state.first() {
+            Some(&STREAMED_STATE_VERSION) => {}
+            Some(&version) => return Err(StateError::UnsupportedVersion(version)),
+            None => {}
+        }
+        if state.len() != STREAMED_STATE_SIZE {
+            return Err(StateError::InvalidLength {
+                expected: STREAMED_STATE_SIZE,
+                found: state.len(),
+            });
+        }
+        if state[1] != T::ID {
+            return Err(StateError::VariantMismatch {
+                expected: T::ID,
+                found: state[1],
+            });
+        }
+
+        let read_u64 = |offset: usize| unsafe { wy_read_8(&state[offset..]) };
+        let len = read_u64(58);
+        let off = state[66] as u64;
+        let consistent = if len <= 48 {
+            off == len
+        } else {
+            off > 0 && off <= 48 && (len - off) % 48 == 0
+        };
+        if !consistent || len > usize::MAX as u64 {
+            return Err(StateError::Corrupted);
+        }
+
+        let mut buffer = [0u8; 48];
+        buffer.copy_from_slice(&state[67..115]);
+        Ok(Self {
+            initial_seed: read_u64(115),
+            seed: read_u64(2),
+            see1: read_u64(10),
+            see2: read_u64(18),
+            secret: [read_u64(26), read_u64(34), read_u64(42), read_u64(50)],
+            buffer,
+            len: len as usize,
+            off: off as usize,
+            _marker: PhantomData,
+        })
+    }
+}
+
+///
+/// The error returned by [`StreamedWyHasher::from_state_bytes`] when the given state can't
+/// be restored
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateError {
+    /// The state doesn't have the expected size
+    InvalidLength { expected: usize, found: usize },
+    /// The state was exported with an unsupported encoding version
+    UnsupportedVersion(u8),
+    /// The state was exported by a hasher of another variant
+    VariantMismatch { expected: u8, found: u8 },
+    /// The length and buffer offset recorded in the state are inconsistent
+    Corrupted,
+}
+
+impl core::fmt::Display for StateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StateError::InvalidLength { expected, found } => write!(
+                f,
+                "invalid state length: expected {} bytes, found {}",
+                expected, found
+            ),
+            StateError::UnsupportedVersion(version) => {
+                write!(f, "unsupported state version: {}", version)
+            }
+            StateError::VariantMismatch { expected, found } => write!(
+                f,
+                "state of variant {} can't be restored as variant {}",
+                found, expected
+            ),
+            StateError::Corrupted => write!(f, "corrupted state"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StateError {}
+
+impl<T: WyHashVariant> core::hash::Hasher for StreamedWyHasher<T> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        StreamedWyHasher::finish(self)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        StreamedWyHasher::write(self, bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: WyHashVariant> std::io::Write for StreamedWyHasher<T> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        StreamedWyHasher::write(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        StreamedWyHasher::write(self, buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+///
+/// A [`core::hash::BuildHasher`] that creates [`StreamedWyHasher`] instances.
+///
+/// Unlike using [`WyHasher`] as a [`core::hash::Hasher`], where each write rehashes the
+/// accumulated state, the hashers created by this builder are stream-equivalent: the value
+/// returned by `finish()` equals [`WyHasher::hash`] of the concatenation of all written bytes.
+///
+#[derive(Clone)]
+pub struct BuildStreamedWyHasher<T: WyHashVariant> {
+    hasher: WyHasher<T>,
+}
+
+impl<T: WyHashVariant> Default for BuildStreamedWyHasher<T> {
+    fn default() -> Self {
+        WyHasher::<T>::default().build_streamed()
+    }
+}
+
+impl<T: WyHashVariant> BuildStreamedWyHasher<T> {
+    ///
+    /// Create a new [`BuildStreamedWyHasher`] instance with given seed and secret
+    ///
+    pub fn new<S: Into<[u64; 4]>>(seed: u64, secret: S) -> Self {
+        WyHasher::<T>::new(seed, secret).build_streamed()
+    }
+}
+
+impl<T: WyHashVariant> core::hash::BuildHasher for BuildStreamedWyHasher<T> {
+    type Hasher = StreamedWyHasher<T>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        self.hasher.streamed()
+    }
+}
+
+///
+/// Implement `const fn` hashing, secret generation and hasher construction for a variant, given
+/// a `const fn` computing the `mul_mum` of it, as trait methods can't be called in const context.
+///
+/// The generated functions follow the reference implementation and produce the same results as
+/// their counterparts of [`WyHashVariant`] and [`WyHasher`].
+///
+#[cfg(any(
+    feature = "wyhash32",
+    feature = "wyhash32condom",
+    feature = "wyhash64",
+    feature = "wyhash64condom"
+))]
+macro_rules! impl_const_hash {
+    ($variant: ty, $mul_mum: ident) => {
+        impl $variant {
+            #[inline(always)]
+            const fn const_mul_mix(a: u64, b: u64) -> u64 {
+                let (a, b) = $mul_mum(a, b);
+                a ^ b
+            }
+
+            ///
+            /// Create a new [`WyHasher`] instance with given seed and secret in const context,
+            /// e.g. for a `static` hasher
+            ///
+            /// [`WyHasher`]: crate::generics::WyHasher
+            ///
+            pub const fn const_with_seed_and_secret(
+                seed: u64,
+                secret: [u64; 4],
+            ) -> crate::generics::WyHasher<Self> {
+                crate::generics::WyHasher::from_mixed_seed(
+                    seed ^ Self::const_mul_mix(seed ^ secret[0], secret[1]),
+                    secret,
+                )
+            }
+
+            ///
+            /// Create a new [`WyHasher`] instance with given seed and default secret in const
+            /// context
+            ///
+            /// [`WyHasher`]: crate::generics::WyHasher
+            ///
+            pub const fn const_with_seed(seed: u64) -> crate::generics::WyHasher<Self> {
+                Self::const_with_seed_and_secret(seed, crate::generics::DEFAULT_SECRET)
+            }
+
+            ///
+            /// Generate a new secret with given seed in const context, the result is identical to
+            /// [`WyHashVariant::generate_secret`]
+            ///
+            /// [`WyHashVariant::generate_secret`]: crate::generics::WyHashVariant::generate_secret
+            ///
+            pub const fn const_generate_secret(mut seed: u64) -> [u64; 4] {
+                use crate::generics::C;
+
+                let mut secret = [0u64; 4];
+                let mut i = 0;
+                while i < 4 {
+                    'restart: loop {
+                        secret[i] = 0;
+                        let mut j = 0;
+                        while j < 8 {
+                            seed = seed.wrapping_add(0xa0761d6478bd642fu64);
+                            let r = Self::const_mul_mix(seed, seed ^ 0xe7037ed1a0b428dbu64);
+                            secret[i] |= C[(r as usize) % C.len()] << (j << 3);
+                            j += 1;
+                        }
+
+                        if secret[i] & 1 == 0 {
+                            continue;
+                        }
+                        let mut j = 0;
+                        while j < i {
+                            if (secret[j] ^ secret[i]).count_ones() != 32 {
+                                continue 'restart;
+                            }
+                            j += 1;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+                secret
+            }
+
+            ///
+            /// One-shot wyhash function with given seed and default secret that can be
+            /// evaluated in const context, its result is identical to [`WyHasher::hash`]
+            ///
+            /// [`WyHasher::hash`]: crate::generics::WyHasher::hash
+            ///
+            pub const fn const_hash(input: &[u8], seed: u64) -> u64 {
+                Self::const_hash_with_secret(input, seed, crate::generics::DEFAULT_SECRET)
+            }
+
+            ///
+            /// One-shot wyhash function with given seed and secret that can be evaluated in
+            /// const context, its result is identical to [`WyHasher::hash`]
+            ///
+            /// [`WyHasher::hash`]: crate::generics::WyHasher::hash
+            ///
+            pub const fn const_hash_with_secret(input: &[u8], seed: u64, secret: [u64; 4]) -> u64 {
+                use crate::util::{wy_read_4_const, wy_read_8_const, wy_read_tail3_const};
+
+                let mix = Self::const_mul_mix;
+                let len = input.len();
+                let mut seed = seed ^ mix(seed ^ secret[0], secret[1]);
+                let a;
+                let b;
+                if len <= 16 {
+                    if len >= 4 {
+                        let quarter = (len >> 3) << 2;
+                        a = (wy_read_4_const(input, 0) << 32) | wy_read_4_const(input, quarter);
+                        b = (wy_read_4_const(input, len - 4) << 32)
+                            | wy_read_4_const(input, len - 4 - quarter);
+                    } else if len > 0 {
+                        a = wy_read_tail3_const(input);
+                        b = 0;
+                    } else {
+                        a = 0;
+                        b = 0;
+                    }
+                } else {
+                    let mut p = 0;
+                    let mut i = len;
+                    if i > 48 {
+                        let mut see1 = seed;
+                        let mut see2 = seed;
+                        loop {
+                            seed = mix(
+                                wy_read_8_const(input, p) ^ secret[1],
+                                wy_read_8_const(input, p + 8) ^ seed,
+                            );
+                            see1 = mix(
+                                wy_read_8_const(input, p + 16) ^ secret[2],
+                                wy_read_8_const(input, p + 24) ^ see1,
+                            );
+                            see2 = mix(
+                                wy_read_8_const(input, p + 32) ^ secret[3],
+                                wy_read_8_const(input, p + 40) ^ see2,
+                            );
+                            p += 48;
+                            i -= 48;
+                            if i <= 48 {
+                                break;
+                            }
+                        }
+                        seed ^= see1 ^ see2;
+                    }
+                    while i > 16 {
+                        seed = mix(
+                            wy_read_8_const(input, p) ^ secret[1],
+                            wy_read_8_const(input, p + 8) ^ seed,
+                        );
+                        p += 16;
+                        i -= 16;
+                    }
+                    a = wy_read_8_const(input, p + i - 16);
+                    b = wy_read_8_const(input, p + i - 8);
+                }
+
+                let (a, b) = $mul_mum(a ^ secret[1], b ^ seed);
+                mix(a ^ secret[0] ^ (len as u64), b ^ secret[1])
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    feature = "wyhash32",
+    feature = "wyhash32condom",
+    feature = "wyhash64",
+    feature = "wyhash64condom"
+))]
+pub(crate) use impl_const_hash;
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 pub(crate) mod test {
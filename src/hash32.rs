@@ -0,0 +1,272 @@
+use crate::util::{wy_read_4, wy_read_tail3};
+
+///
+/// The 32-bit output wyhash function of `wyhash32.h` from the reference implementation, which is
+/// designed for 32-bit hash tables and platforms without fast 64-bit multiplication.
+///
+/// Note that this is a different algorithm from the [`crate::WyHash32`] variant, which produces
+/// 64-bit hash values of wyhash final4 with 32-bit multiplications. And it's known to be weak
+/// when used with the seeds `0x429dacdd` and `0xd637dbf3`, which should be avoided.
+///
+pub fn wyhash32(input: &[u8], seed: u32) -> u32 {
+    let len = input.len() as u64;
+    let mut seed = seed ^ ((len >> 32) as u32);
+    let mut see1 = len as u32;
+    mix32(&mut seed, &mut see1);
+
+    let mut p = input;
+    while p.len() > 8 {
+        unsafe {
+            seed ^= wy_read_4(p) as u32;
+            see1 ^= wy_read_4(&p[4..]) as u32;
+        }
+        mix32(&mut seed, &mut see1);
+        p = &p[8..];
+    }
+    finish32(seed, see1, p)
+}
+
+#[inline(always)]
+fn mix32(a: &mut u32, b: &mut u32) {
+    let c = ((*a ^ 0x53c5ca59) as u64) * ((*b ^ 0x74743c1b) as u64);
+    *a = c as u32;
+    *b = (c >> 32) as u32;
+}
+
+#[inline(always)]
+fn finish32(mut seed: u32, mut see1: u32, tail: &[u8]) -> u32 {
+    let len = tail.len();
+    unsafe {
+        if len >= 4 {
+            seed ^= wy_read_4(tail) as u32;
+            see1 ^= wy_read_4(&tail[len - 4..]) as u32;
+        } else if len > 0 {
+            seed ^= wy_read_tail3(tail) as u32;
+        }
+    }
+    mix32(&mut seed, &mut see1);
+    mix32(&mut seed, &mut see1);
+    seed ^ see1
+}
+
+///
+/// The streamed version of [`wyhash32`].
+///
+/// As the length of input is mixed into the state before hashing any byte, the total length
+/// must be given when creating the hasher.
+///
+#[derive(Clone)]
+pub struct StreamedWyHash32 {
+    seed: u32,
+    see1: u32,
+    buffer: [u8; 8],
+    off: usize,
+    len: u64,
+    written: u64,
+}
+
+impl StreamedWyHash32 {
+    ///
+    /// Create a new [`StreamedWyHash32`] instance for hashing an input of `len` bytes with
+    /// given seed
+    ///
+    pub fn new(seed: u32, len: u64) -> Self {
+        let mut seed = seed ^ ((len >> 32) as u32);
+        let mut see1 = len as u32;
+        mix32(&mut seed, &mut see1);
+        Self {
+            seed,
+            see1,
+            buffer: [0; 8],
+            off: 0,
+            len,
+            written: 0,
+        }
+    }
+
+    pub fn write(&mut self, mut bytes: &[u8]) {
+        self.written += bytes.len() as u64;
+        while !bytes.is_empty() {
+            // The last 8 bytes or less are handled when finishing, so a full buffer is
+            // processed only when there are more bytes
+            if self.off == 8 {
+                unsafe {
+                    self.seed ^= wy_read_4(&self.buffer) as u32;
+                    self.see1 ^= wy_read_4(&self.buffer[4..]) as u32;
+                }
+                mix32(&mut self.seed, &mut self.see1);
+                self.off = 0;
+            }
+            let n = bytes.len().min(8 - self.off);
+            self.buffer[self.off..(self.off + n)].copy_from_slice(&bytes[..n]);
+            self.off += n;
+            bytes = &bytes[n..];
+        }
+    }
+
+    ///
+    /// Get the hash of all the bytes written
+    ///
+    /// Panics if the number of bytes written differs from the length given when creating
+    /// this hasher.
+    ///
+    pub fn finish(&self) -> u32 {
+        assert_eq!(
+            self.written, self.len,
+            "the number of bytes written differs from the declared length"
+        );
+        finish32(self.seed, self.see1, &self.buffer[..self.off])
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use super::*;
+    use crate::generics::test::EXTENDED_TEST_VECTOR_BUFFER;
+
+    #[rustfmt::skip]
+    const TEST_VECTOR: [(&str, u32, u32); 10] = [
+        ("", 0, 0xa45f982f),
+        ("a", 1, 0x09021114),
+        ("abc", 2, 0xfe40215d),
+        ("wyhash", 3, 0x80dbe766),
+        ("message digest", 4, 0x40b04d40),
+        ("abcdefghijklmnopqrstuvwxyz", 5, 0x79505519),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789", 6, 0x8cdac2ed),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", 7, 0xa690cb1d),
+        ("12345678901234567890123456789012345678901234567890123456789012345678901234567890", 8, 0xbbaf6442),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!@#$%^&*()`~-_=+[{]};:,<.>/?", 9, 0x48499107),
+    ];
+
+    #[rustfmt::skip]
+    const EXTENDED_TEST_VECTOR: [(usize, u32); 90] = [
+        (0, 0xa45f982f),
+        (1, 0x80eeeb42),
+        (2, 0xff830c63),
+        (3, 0x2ecd87c2),
+        (4, 0xae0db10d),
+        (5, 0x457dd2c1),
+        (6, 0xa1ce15ce),
+        (7, 0x39cb4684),
+        (8, 0xc1f36cbd),
+        (9, 0x317d1b93),
+        (10, 0xf77c8e0c),
+        (11, 0xd3b2d72d),
+        (12, 0x66a1214a),
+        (13, 0xcb2a5359),
+        (14, 0xf18eafe5),
+        (15, 0xe61cb49e),
+        (16, 0xf34a1623),
+        (17, 0x01a29699),
+        (18, 0xcfc0337a),
+        (19, 0x100533d1),
+        (20, 0x8d71fbf3),
+        (21, 0x5e83b09a),
+        (22, 0x0022e80e),
+        (23, 0x96ed4c52),
+        (24, 0xf37802cb),
+        (25, 0x86876ab2),
+        (26, 0x5b8e085e),
+        (27, 0x0fb9445c),
+        (28, 0xd57ca21e),
+        (29, 0xd3cfd02b),
+        (30, 0xb7d597fb),
+        (31, 0xa01eec51),
+        (32, 0x98a3fb3c),
+        (33, 0xd0343d1c),
+        (34, 0xa2a6a010),
+        (35, 0x6ca54707),
+        (36, 0x429c672a),
+        (37, 0x297e4e85),
+        (38, 0x91b0dd95),
+        (39, 0xbf492432),
+        (40, 0xeb180ad4),
+        (41, 0xb77dd9af),
+        (42, 0x6c7af1a4),
+        (43, 0xcb478f05),
+        (44, 0xf53f9080),
+        (45, 0x33118e85),
+        (46, 0xd2347d01),
+        (47, 0x04719a81),
+        (48, 0x5adb27d2),
+        (49, 0x84f43510),
+        (50, 0x587d2336),
+        (51, 0x4e9f9fdf),
+        (52, 0x9e911103),
+        (53, 0x7f1289b1),
+        (54, 0x230b29ef),
+        (55, 0xd4b6fd4b),
+        (56, 0x5e77a203),
+        (57, 0x142140c6),
+        (58, 0xb06190f8),
+        (59, 0xc1cc141f),
+        (60, 0xe377d5b6),
+        (61, 0x744c2f37),
+        (62, 0x4bb32eeb),
+        (63, 0x241085f7),
+        (64, 0x8623f38b),
+        (65, 0x881ee154),
+        (66, 0xc8cc1b64),
+        (67, 0x0982c2d1),
+        (68, 0x62d3d25e),
+        (69, 0xcd650f7b),
+        (70, 0x42160ce2),
+        (71, 0x82d46f51),
+        (72, 0x5e4fee3b),
+        (73, 0x75b65496),
+        (74, 0xc37c7451),
+        (75, 0x25814678),
+        (76, 0x01ebfa65),
+        (77, 0xc9c7bd7e),
+        (78, 0x66df60ca),
+        (79, 0x7f78d140),
+        (80, 0x3d55c888),
+        (81, 0x1a8ab493),
+        (82, 0xf9751741),
+        (83, 0x2d8805fa),
+        (84, 0xcaaff8d1),
+        (85, 0x800c0d55),
+        (86, 0x934b1933),
+        (87, 0x76140fd0),
+        (88, 0x930d6437),
+        (89, 0x58e8c1a5),
+    ];
+
+    #[test]
+    fn test() {
+        for (input, seed, result) in TEST_VECTOR.iter().cloned() {
+            assert_eq!(wyhash32(input.as_bytes(), seed), result, "seed: {}", seed);
+        }
+    }
+
+    #[test]
+    fn extended_test() {
+        for (seed_and_len, result) in EXTENDED_TEST_VECTOR.iter().cloned() {
+            let input = &EXTENDED_TEST_VECTOR_BUFFER[..seed_and_len];
+            assert_eq!(wyhash32(input, seed_and_len as u32), result);
+
+            for chunksize in 1..=17 {
+                let mut hasher = StreamedWyHash32::new(seed_and_len as u32, input.len() as u64);
+                for chunk in input.chunks(chunksize) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(
+                    hasher.finish(),
+                    result,
+                    "seed_and_len: {}, chunksize: {}",
+                    seed_and_len,
+                    chunksize
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch() {
+        let mut hasher = StreamedWyHash32::new(0, 4);
+        hasher.write(b"abc");
+        hasher.finish();
+    }
+}
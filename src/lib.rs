@@ -31,9 +31,16 @@
 //! with previous versions, but resolves the issue of bad seeds that found
 //! in previous versions.
 //!
+//! This crate is `no_std` when the `std` feature is disabled, while [`core::hash::Hasher`] and
+//! [`core::hash::BuildHasher`] are implemented regardless.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod generics;
+pub mod secret;
 mod util;
+pub mod wyrand;
 
 #[cfg(feature = "wyhash32")]
 pub mod wyhash32;
@@ -58,3 +65,75 @@ pub mod wyhash64condom;
 
 #[cfg(feature = "wyhash64condom")]
 pub use wyhash64condom::*;
+
+///
+/// The variant that is the fastest on the target platform, namely [`WyHash64`] on 64-bit
+/// targets, and [`WyHash32`] otherwise.
+///
+/// Note that the two variants produce different hash values, so this alias must not be used
+/// when the hash values are persisted or shared across platforms.
+///
+#[cfg(all(target_pointer_width = "64", feature = "wyhash64"))]
+pub type NativeWyHash = WyHash64;
+
+///
+/// The variant that is the fastest on the target platform, namely [`WyHash64`] on 64-bit
+/// targets, and [`WyHash32`] otherwise.
+///
+/// Note that the two variants produce different hash values, so this alias must not be used
+/// when the hash values are persisted or shared across platforms.
+///
+#[cfg(all(not(target_pointer_width = "64"), feature = "wyhash32"))]
+pub type NativeWyHash = WyHash32;
+
+///
+/// The condom variant that is the fastest on the target platform, namely [`WyHash64Condom`] on
+/// 64-bit targets, and [`WyHash32Condom`] otherwise.
+///
+/// Note that the two variants produce different hash values, so this alias must not be used
+/// when the hash values are persisted or shared across platforms.
+///
+#[cfg(all(target_pointer_width = "64", feature = "wyhash64condom"))]
+pub type NativeWyHashCondom = WyHash64Condom;
+
+///
+/// The condom variant that is the fastest on the target platform, namely [`WyHash64Condom`] on
+/// 64-bit targets, and [`WyHash32Condom`] otherwise.
+///
+/// Note that the two variants produce different hash values, so this alias must not be used
+/// when the hash values are persisted or shared across platforms.
+///
+#[cfg(all(not(target_pointer_width = "64"), feature = "wyhash32condom"))]
+pub type NativeWyHashCondom = WyHash32Condom;
+
+///
+/// Get the variant that [`NativeWyHash`] refers to on this platform
+///
+#[cfg(all(target_pointer_width = "64", feature = "wyhash64"))]
+pub const fn native_variant() -> dynamic::Variant {
+    dynamic::Variant::WyHash64
+}
+
+///
+/// Get the variant that [`NativeWyHash`] refers to on this platform
+///
+#[cfg(all(not(target_pointer_width = "64"), feature = "wyhash32"))]
+pub const fn native_variant() -> dynamic::Variant {
+    dynamic::Variant::WyHash32
+}
+
+#[cfg(feature = "hash32")]
+pub mod hash32;
+
+#[cfg(feature = "std")]
+pub mod file;
+#[cfg(feature = "std")]
+pub mod tree;
+
+#[cfg(any(
+    feature = "wyhash32",
+    feature = "wyhash32condom",
+    feature = "wyhash64",
+    feature = "wyhash64condom"
+))]
+pub mod dynamic;
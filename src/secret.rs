@@ -0,0 +1,186 @@
+use crate::generics::{WyHashVariant, C, DEFAULT_SECRET};
+
+///
+/// A validated secret for wyhash.
+///
+/// A good secret consists of 4 odd words, whose bytes are all drawn from the bytes with exactly 4
+/// bits set, and each pair of words has a Hamming distance of 32. Those are the invariants that
+/// [`WyHashVariant::generate_secret`] enforces, and they're checked when converting a `[u64; 4]`
+/// into a [`Secret`].
+///
+/// All the constructors taking a secret accept [`Secret`], which is the preferred way to pass a
+/// custom secret.
+///
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Secret([u64; 4]);
+
+impl Secret {
+    ///
+    /// Generate a new secret with given seed, see [`WyHashVariant::generate_secret`]
+    ///
+    pub fn generate<V: WyHashVariant>(seed: u64) -> Self {
+        Self(V::generate_secret(seed))
+    }
+
+    ///
+    /// Get the words of this secret
+    ///
+    pub const fn words(&self) -> [u64; 4] {
+        self.0
+    }
+}
+
+impl Default for Secret {
+    ///
+    /// The default secret of the reference implementation, which is not generated by
+    /// `make_secret` and thus doesn't pass the byte check, but is known to be good.
+    ///
+    fn default() -> Self {
+        Self(DEFAULT_SECRET)
+    }
+}
+
+impl core::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Secret(<redacted>)")
+    }
+}
+
+impl TryFrom<[u64; 4]> for Secret {
+    type Error = SecretError;
+
+    fn try_from(secret: [u64; 4]) -> Result<Self, Self::Error> {
+        for (index, word) in secret.iter().enumerate() {
+            if word & 1 == 0 {
+                return Err(SecretError::EvenWord { index });
+            }
+            for byte in word.to_le_bytes() {
+                if !C.contains(&(byte as u64)) {
+                    return Err(SecretError::InvalidByte { index, byte });
+                }
+            }
+            for (first, other) in secret[..index].iter().enumerate() {
+                let distance = (other ^ word).count_ones();
+                if distance != 32 {
+                    return Err(SecretError::HammingDistance {
+                        first,
+                        second: index,
+                        distance,
+                    });
+                }
+            }
+        }
+        Ok(Self(secret))
+    }
+}
+
+impl From<Secret> for [u64; 4] {
+    fn from(secret: Secret) -> Self {
+        secret.0
+    }
+}
+
+///
+/// The error returned when a `[u64; 4]` is not a good secret
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretError {
+    /// The word at `index` is even
+    EvenWord { index: usize },
+    /// The word at `index` contains a byte that doesn't have exactly 4 bits set
+    InvalidByte { index: usize, byte: u8 },
+    /// The Hamming distance between the words at `first` and `second` is not 32
+    HammingDistance {
+        first: usize,
+        second: usize,
+        distance: u32,
+    },
+}
+
+impl core::fmt::Display for SecretError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SecretError::EvenWord { index } => write!(f, "secret word {} is even", index),
+            SecretError::InvalidByte { index, byte } => write!(
+                f,
+                "secret word {} contains byte {:#04x}, which doesn't have exactly 4 bits set",
+                index, byte
+            ),
+            SecretError::HammingDistance {
+                first,
+                second,
+                distance,
+            } => write!(
+                f,
+                "Hamming distance between secret words {} and {} is {}, rather than 32",
+                first, second, distance
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SecretError {}
+
+#[cfg(test)]
+#[cfg(all(feature = "std", feature = "wyhash64"))]
+mod test {
+    use super::*;
+    use crate::WyHash64;
+
+    #[test]
+    fn test_generated_secret() {
+        for seed in 0..100 {
+            let words = WyHash64::generate_secret(seed);
+            assert_eq!(
+                Secret::try_from(words),
+                Ok(Secret::generate::<WyHash64>(seed))
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_secret() {
+        let words = WyHash64::generate_secret(0);
+
+        let mut even = words;
+        even[2] &= !1;
+        assert_eq!(
+            Secret::try_from(even),
+            Err(SecretError::EvenWord { index: 2 })
+        );
+
+        assert_eq!(
+            Secret::try_from(DEFAULT_SECRET),
+            Err(SecretError::InvalidByte {
+                index: 0,
+                byte: 0x2f
+            })
+        );
+
+        let mut duplicated = words;
+        duplicated[3] = duplicated[1];
+        assert_eq!(
+            Secret::try_from(duplicated),
+            Err(SecretError::HammingDistance {
+                first: 1,
+                second: 3,
+                distance: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_hash_with_secret() {
+        let secret = Secret::generate::<WyHash64>(42);
+        assert_eq!(
+            WyHash64::with_seed_and_secret(1, secret).hash(b"wyhash"),
+            WyHash64::with_seed_and_secret(1, secret.words()).hash(b"wyhash")
+        );
+        assert_eq!(
+            WyHash64::with_seed_and_secret(1, Secret::default()).hash(b"wyhash"),
+            WyHash64::with_seed(1).hash(b"wyhash")
+        );
+        assert_eq!(format!("{:?}", secret), "Secret(<redacted>)");
+    }
+}
@@ -0,0 +1,135 @@
+//!
+//! The tree hash mode for very large inputs.
+//!
+//! In this mode, the input is split into leaves of [`TREE_LEAF_SIZE`] bytes (the last one may be
+//! shorter), and each leaf is hashed independently with [`WyHasher::hash`]. Then the hashes of
+//! adjacent nodes are combined level by level into a binary tree, where an unpaired node at the
+//! end of a level is promoted to the next level as is. The root is finally mixed with the length
+//! of the input.
+//!
+//! As the leaves are independent, they can be hashed in parallel with [`par_tree_hash`] when the
+//! `rayon` feature is enabled, and the shape of the tree depends only on the length of the input,
+//! so the result is the same regardless of the number of threads.
+//!
+//! Note that the tree hash is a different hash function from [`WyHasher::hash`], and they produce
+//! different values for the same input.
+//!
+
+use crate::generics::{WyHashVariant, WyHasher};
+
+///
+/// The size of leaves in the tree hash mode
+///
+pub const TREE_LEAF_SIZE: usize = 1 << 20;
+
+///
+/// Hash the input in the tree hash mode with the seed and secret of given hasher on the current
+/// thread
+///
+pub fn tree_hash<V: WyHashVariant>(hasher: &WyHasher<V>, input: &[u8]) -> u64 {
+    let leaves = input
+        .chunks(TREE_LEAF_SIZE)
+        .map(|leaf| hasher.hash(leaf))
+        .collect();
+    finish_tree(hasher, leaves, input.len())
+}
+
+///
+/// Hash the input in the tree hash mode with the seed and secret of given hasher, where the
+/// leaves are hashed in parallel on the current rayon thread pool
+///
+/// The result is identical to [`tree_hash`].
+///
+#[cfg(feature = "rayon")]
+pub fn par_tree_hash<V: WyHashVariant + Sync>(hasher: &WyHasher<V>, input: &[u8]) -> u64 {
+    use rayon::prelude::*;
+
+    let leaves = input
+        .par_chunks(TREE_LEAF_SIZE)
+        .map(|leaf| hasher.hash(leaf))
+        .collect();
+    finish_tree(hasher, leaves, input.len())
+}
+
+fn combine<V: WyHashVariant>(hasher: &WyHasher<V>, left: u64, right: u64) -> u64 {
+    let mut node = [0u8; 16];
+    node[..8].copy_from_slice(&left.to_le_bytes());
+    node[8..].copy_from_slice(&right.to_le_bytes());
+    hasher.hash(&node)
+}
+
+fn finish_tree<V: WyHashVariant>(hasher: &WyHasher<V>, mut nodes: Vec<u64>, len: usize) -> u64 {
+    if nodes.is_empty() {
+        nodes.push(hasher.hash(&[]));
+    }
+    while nodes.len() > 1 {
+        nodes = nodes
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => combine(hasher, *left, *right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    combine(hasher, nodes[0], len as u64)
+}
+
+#[cfg(test)]
+#[cfg(feature = "wyhash64")]
+mod test {
+    use super::*;
+    use crate::WyHash64;
+
+    fn input(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 131 + (i >> 11)) as u8).collect()
+    }
+
+    #[test]
+    fn test_tree_shape() {
+        let hasher = WyHash64::with_seed(9);
+        let input = input(TREE_LEAF_SIZE * 3 + 5);
+        let leaves: Vec<u64> = input
+            .chunks(TREE_LEAF_SIZE)
+            .map(|leaf| hasher.hash(leaf))
+            .collect();
+        let root = combine(
+            &hasher,
+            combine(&hasher, leaves[0], leaves[1]),
+            combine(&hasher, leaves[2], leaves[3]),
+        );
+        assert_eq!(
+            tree_hash(&hasher, &input),
+            combine(&hasher, root, input.len() as u64)
+        );
+
+        assert_ne!(tree_hash(&hasher, b""), tree_hash(&hasher, b"\0"));
+        assert_ne!(
+            tree_hash(&hasher, &input[..TREE_LEAF_SIZE]),
+            tree_hash(&hasher, &input[..TREE_LEAF_SIZE + 1])
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel() {
+        let hasher = WyHash64::with_seed(9);
+        for len in [0, 1, TREE_LEAF_SIZE, TREE_LEAF_SIZE * 5 + 17] {
+            let input = input(len);
+            let expected = tree_hash(&hasher, &input);
+            for threads in [1, 2, 3, 8] {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                assert_eq!(
+                    pool.install(|| par_tree_hash(&hasher, &input)),
+                    expected,
+                    "len: {}, threads: {}",
+                    len,
+                    threads
+                );
+            }
+        }
+    }
+}
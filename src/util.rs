@@ -1,5 +1,6 @@
+#[cfg(any(feature = "wyhash32", feature = "wyhash32condom"))]
 #[inline(always)]
-pub(crate) fn wy_rotate(x: u64) -> u64 {
+pub(crate) const fn wy_rotate(x: u64) -> u64 {
     x.rotate_left(32)
 }
 
@@ -70,6 +71,63 @@ pub(crate) unsafe fn wy_read_tail3(input: &[u8]) -> u64 {
     }
 }
 
+///
+/// Read 8 bytes at `offset` of a given slice as a u64 in little-endian order, in const context.
+///
+#[cfg(any(
+    feature = "wyhash32",
+    feature = "wyhash32condom",
+    feature = "wyhash64",
+    feature = "wyhash64condom"
+))]
+#[inline(always)]
+pub(crate) const fn wy_read_8_const(input: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes([
+        input[offset],
+        input[offset + 1],
+        input[offset + 2],
+        input[offset + 3],
+        input[offset + 4],
+        input[offset + 5],
+        input[offset + 6],
+        input[offset + 7],
+    ])
+}
+
+///
+/// Read 4 bytes at `offset` of a given slice as a u64 in little-endian order, in const context.
+///
+#[cfg(any(
+    feature = "wyhash32",
+    feature = "wyhash32condom",
+    feature = "wyhash64",
+    feature = "wyhash64condom"
+))]
+#[inline(always)]
+pub(crate) const fn wy_read_4_const(input: &[u8], offset: usize) -> u64 {
+    u32::from_le_bytes([
+        input[offset],
+        input[offset + 1],
+        input[offset + 2],
+        input[offset + 3],
+    ]) as u64
+}
+
+///
+/// The const version of [`wy_read_tail3`], the slice must be 1 to 3 bytes long.
+///
+#[cfg(any(
+    feature = "wyhash32",
+    feature = "wyhash32condom",
+    feature = "wyhash64",
+    feature = "wyhash64condom"
+))]
+#[inline(always)]
+pub(crate) const fn wy_read_tail3_const(input: &[u8]) -> u64 {
+    let len = input.len();
+    ((input[0] as u64) << 16) | ((input[len >> 1] as u64) << 8) | input[len - 1] as u64
+}
+
 #[inline(always)]
 #[cold]
 fn cold_path() {}
@@ -15,20 +15,32 @@ pub struct WyHash32Condom {
 impl WyHashVariant for WyHash32Condom {
     #[inline(always)]
     fn mul_mum(a: u64, b: u64) -> (u64, u64) {
-        let hh = (a >> 32) * (b >> 32);
-        let ll = (a as u32 as u64) * (b as u32 as u64);
-        let hl = (a >> 32) * ((b as u32) as u64);
-        let lh = ((a as u32) as u64) * (b >> 32);
-        (a ^ wy_rotate(hl) ^ hh, b ^ wy_rotate(lh) ^ ll)
+        mul_mum(a, b)
     }
 }
 
+impl crate::generics::VariantTag for WyHash32Condom {
+    const ID: u8 = 4;
+}
+
+crate::generics::impl_const_hash!(WyHash32Condom, mul_mum);
+
+#[inline(always)]
+const fn mul_mum(a: u64, b: u64) -> (u64, u64) {
+    let hh = (a >> 32) * (b >> 32);
+    let ll = (a as u32 as u64) * (b as u32 as u64);
+    let hl = (a >> 32) * ((b as u32) as u64);
+    let lh = ((a as u32) as u64) * (b >> 32);
+    (a ^ wy_rotate(hl) ^ hh, b ^ wy_rotate(lh) ^ ll)
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod test {
 
     use super::*;
     use crate::generics::test::*;
+    use crate::generics::WyHasher;
 
     const TEST_VECTOR: TestVector = [
         ("",0, 0xeea54221671289db),
@@ -43,6 +55,21 @@ mod test {
         ("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!@#$%^&*()`~-_=+[{]};:,<.>/?",9, 0x1720cb8c72189461),
     ];
 
+    const HASH_U64_PAIR_TEST_VECTOR: [(u64, u64, u64); 4] = [
+        (0x0, 0x0, 0x0cd5982e6aa82194u64),
+        (0x1, 0x2, 0x26ef6ef698a33f8eu64),
+        (
+            0x123456789abcdef0,
+            0xfedcba9876543210,
+            0x45edcd7d74091f33u64,
+        ),
+        (
+            0xa0761d6478bd642f,
+            0xe7037ed1a0b428db,
+            0x20fad90ed5ee420du64,
+        ),
+    ];
+
     #[rustfmt::skip]
     const GENERATE_SECRET_TEST_VECTOR: [(u64, [u64; 4]); 10] = [
         (0x0, [ 0x1b99a3391da93a1d, 0x78a6a9a98e8795b1, 0xe45cd83956d4591d, 0xe4968be12bb86ac3]),
@@ -224,4 +251,185 @@ mod test {
             assert_eq!(WyHash32Condom::generate_secret(seed), result);
         }
     }
+
+    #[test]
+    fn test_std_hasher() {
+        use std::hash::{BuildHasher, Hasher};
+
+        for (input, seed, result) in TEST_VECTOR.iter().cloned() {
+            let input = input.as_bytes();
+            let builder = WyHash32Condom::with_seed(seed).build_streamed();
+            for chunksize in 1..=48 {
+                let mut hasher = builder.build_hasher();
+                for chunk in input.chunks(chunksize) {
+                    Hasher::write(&mut hasher, chunk);
+                }
+                assert_eq!(Hasher::finish(&hasher), result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_std_hasher_integers() {
+        use std::collections::HashSet;
+        use std::hash::Hasher;
+
+        let hasher = WyHash32Condom::with_seed(0);
+        let mut hashes = HashSet::new();
+        for i in 0..10000u64 {
+            let mut h = hasher.clone();
+            h.write_u64(i);
+            assert!(hashes.insert(h.finish()), "collision at {}", i);
+
+            let mut h2 = hasher.clone();
+            h2.write_u32(i as u32);
+            assert_eq!(h.finish(), h2.finish());
+        }
+
+        let mut h1 = hasher.clone();
+        h1.write_u64(1);
+        h1.write_u64(2);
+        let mut h2 = hasher.clone();
+        h2.write_u64(2);
+        h2.write_u64(1);
+        assert_ne!(h1.finish(), h2.finish());
+
+        // A word equal to `secret[0]` must not erase the words written before it
+        let secret0 = crate::generics::DEFAULT_SECRET[0];
+        let mut pairs = HashSet::new();
+        let mut wide = HashSet::new();
+        for i in 0..1000u64 {
+            let mut h = hasher.clone();
+            h.write_u64(i);
+            h.write_u64(secret0);
+            assert!(pairs.insert(h.finish()), "collision at ({}, secret[0])", i);
+
+            let mut h = hasher.clone();
+            h.write_u128(((i as u128) << 64) | secret0 as u128);
+            assert!(wide.insert(h.finish()), "collision at u128 {}", i);
+        }
+    }
+
+    #[test]
+    fn test_hash_reader() {
+        for (seed_and_len, result) in EXTENDED_TEST_VECTOR.iter() {
+            let input = &EXTENDED_TEST_VECTOR_BUFFER[..*seed_and_len];
+            let hasher = WyHash32Condom::with_seed(*seed_and_len as u64);
+            assert_eq!(hasher.hash_reader(input).unwrap(), *result);
+        }
+        assert_eq!(
+            WyHash32Condom::hash_reader(EXTENDED_TEST_VECTOR_BUFFER).unwrap(),
+            WyHash32Condom::hash(EXTENDED_TEST_VECTOR_BUFFER)
+        );
+    }
+
+    #[test]
+    fn test_streamed_long_input() {
+        let input: Vec<u8> = (0..400u32).map(|i| (i * 31 + 7) as u8).collect();
+        let hasher = WyHash32Condom::with_seed(42);
+        for len in 0..input.len() {
+            let input = &input[..len];
+            for chunksize in [1, 15, 16, 17, 47, 48, 49, 95, 96, 97, 144, 200] {
+                let mut streamed = hasher.streamed();
+                for chunk in input.chunks(chunksize) {
+                    streamed.write(chunk);
+                }
+                assert_eq!(
+                    streamed.finish(),
+                    hasher.hash(input),
+                    "len: {}, chunksize: {}",
+                    len,
+                    chunksize
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_resume_from_state() {
+        let input: Vec<u8> = (0..300u32).map(|i| (i * 13 + 5) as u8).collect();
+        let hasher = WyHash32Condom::with_seed(7);
+        for split in 0..input.len() {
+            let mut streamed = hasher.streamed();
+            streamed.write(&input[..split]);
+            let state = streamed.to_state_bytes();
+
+            let mut resumed =
+                crate::generics::StreamedWyHasher::<WyHash32Condom>::from_state_bytes(&state)
+                    .unwrap();
+            resumed.write(&input[split..]);
+            assert_eq!(resumed.finish(), hasher.hash(&input), "split: {}", split);
+        }
+    }
+
+    #[test]
+    fn test_hash_u64_pair() {
+        for (a, b, result) in HASH_U64_PAIR_TEST_VECTOR.iter().cloned() {
+            assert_eq!(WyHash32Condom::hash_u64_pair(a, b), result);
+        }
+    }
+
+    #[test]
+    fn test_const_hash() {
+        const HASH: u64 = WyHash32Condom::const_hash(b"wyhash", 3);
+        assert_eq!(HASH, TEST_VECTOR[3].2);
+
+        for (input, seed, result) in TEST_VECTOR.iter().cloned() {
+            assert_eq!(WyHash32Condom::const_hash(input.as_bytes(), seed), result);
+        }
+        for (seed_and_len, result) in EXTENDED_TEST_VECTOR.iter().cloned() {
+            let input = &EXTENDED_TEST_VECTOR_BUFFER[..seed_and_len];
+            assert_eq!(
+                WyHash32Condom::const_hash(input, seed_and_len as u64),
+                result
+            );
+        }
+
+        let input: Vec<u8> = (0..400u32).map(|i| (i * 31 + 7) as u8).collect();
+        let secret = WyHash32Condom::generate_secret(42);
+        let hasher = WyHash32Condom::with_seed_and_secret(42, secret);
+        for len in 0..input.len() {
+            assert_eq!(
+                WyHash32Condom::const_hash_with_secret(&input[..len], 42, secret),
+                hasher.hash(&input[..len])
+            );
+        }
+    }
+
+    #[test]
+    fn test_const_hasher() {
+        const SECRET: [u64; 4] =
+            WyHash32Condom::const_generate_secret(GENERATE_SECRET_TEST_VECTOR[9].0);
+        static HASHER: WyHasher<WyHash32Condom> =
+            WyHash32Condom::const_with_seed_and_secret(7, SECRET);
+        static DEFAULT_HASHER: WyHasher<WyHash32Condom> = WyHash32Condom::const_with_seed(7);
+
+        assert_eq!(SECRET, GENERATE_SECRET_TEST_VECTOR[9].1);
+        for (seed, result) in GENERATE_SECRET_TEST_VECTOR.iter().cloned() {
+            assert_eq!(WyHash32Condom::const_generate_secret(seed), result);
+        }
+        for input in EXTENDED_TEST_VECTOR_BUFFER.chunks(13) {
+            assert_eq!(
+                HASHER.hash(input),
+                WyHash32Condom::with_seed_and_secret(7, SECRET).hash(input)
+            );
+            assert_eq!(
+                DEFAULT_HASHER.hash(input),
+                WyHash32Condom::with_seed(7).hash(input)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_batch() {
+        let hasher = WyHash32Condom::with_seed(5);
+        let inputs: Vec<&[u8]> = (0..=EXTENDED_TEST_VECTOR_BUFFER.len())
+            .map(|len| &EXTENDED_TEST_VECTOR_BUFFER[..len])
+            .collect();
+        let mut output = vec![0u64; inputs.len()];
+        hasher.hash_batch(&inputs, &mut output);
+        for (input, result) in inputs.iter().zip(output) {
+            assert_eq!(hasher.hash(input), result, "len: {}", input.len());
+        }
+    }
 }
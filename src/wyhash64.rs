@@ -12,16 +12,28 @@ pub struct WyHash64 {
 impl WyHashVariant for WyHash64 {
     #[inline(always)]
     fn mul_mum(a: u64, b: u64) -> (u64, u64) {
-        let m = (a as u128) * (b as u128);
-        (m as u64, (m >> 64) as u64)
+        mul_mum(a, b)
     }
 }
 
+impl crate::generics::VariantTag for WyHash64 {
+    const ID: u8 = 1;
+}
+
+crate::generics::impl_const_hash!(WyHash64, mul_mum);
+
+#[inline(always)]
+const fn mul_mum(a: u64, b: u64) -> (u64, u64) {
+    let m = (a as u128) * (b as u128);
+    (m as u64, (m >> 64) as u64)
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod test {
     use super::*;
     use crate::generics::test::*;
+    use crate::generics::WyHasher;
 
     const TEST_VECTOR: TestVector = [
         ("",0, 0x409638ee2bde459),
@@ -36,6 +48,21 @@ mod test {
         ("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!@#$%^&*()`~-_=+[{]};:,<.>/?",9, 0x3903fc47ba3ef81c),
     ];
 
+    const HASH_U64_PAIR_TEST_VECTOR: [(u64, u64, u64); 4] = [
+        (0x0, 0x0, 0x60c06e5aa6716029u64),
+        (0x1, 0x2, 0x12f633807d6dd48cu64),
+        (
+            0x123456789abcdef0,
+            0xfedcba9876543210,
+            0x9fd889808c024ed9u64,
+        ),
+        (
+            0xa0761d6478bd642f,
+            0xe7037ed1a0b428db,
+            0x1ff5c2923a788d2cu64,
+        ),
+    ];
+
     #[rustfmt::skip]
     const GENERATE_SECRET_TEST_VECTOR: [(u64, [u64; 4]); 10] = [
         (0x0, [0x95d49a959ca5a395, 0xb4a9716ac94da695, 0x5635cc6355956559, 0xe1e18e3a9c591da9]),
@@ -218,4 +245,302 @@ mod test {
             assert_eq!(WyHash64::generate_secret(seed), result);
         }
     }
+
+    #[test]
+    fn test_std_hasher() {
+        use std::hash::{BuildHasher, Hasher};
+
+        for (input, seed, result) in TEST_VECTOR.iter().cloned() {
+            let input = input.as_bytes();
+            let builder = WyHash64::with_seed(seed).build_streamed();
+            for chunksize in 1..=48 {
+                let mut hasher = builder.build_hasher();
+                for chunk in input.chunks(chunksize) {
+                    Hasher::write(&mut hasher, chunk);
+                }
+                assert_eq!(Hasher::finish(&hasher), result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_streamed_default() {
+        use crate::generics::BuildStreamedWyHasher;
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+
+        let long_key = &EXTENDED_TEST_VECTOR_BUFFER.repeat(3)[..];
+        let mut map = HashMap::<&[u8], u32, BuildStreamedWyHasher<WyHash64>>::default();
+        map.insert(long_key, 1);
+        map.insert(b"abc", 2);
+        assert_eq!(map.get(long_key), Some(&1));
+        assert_eq!(map.get(&b"abc"[..]), Some(&2));
+
+        let mut hasher = BuildStreamedWyHasher::<WyHash64>::default().build_hasher();
+        hasher.write(&long_key[..100]);
+        hasher.write(&long_key[100..]);
+        assert_eq!(hasher.finish(), WyHash64::hash(long_key));
+    }
+
+    #[test]
+    fn test_std_hasher_integers() {
+        use std::collections::HashSet;
+        use std::hash::Hasher;
+
+        let hasher = WyHash64::with_seed(0);
+        let mut hashes = HashSet::new();
+        for i in 0..10000u64 {
+            let mut h = hasher.clone();
+            h.write_u64(i);
+            assert!(hashes.insert(h.finish()), "collision at {}", i);
+
+            let mut h2 = hasher.clone();
+            h2.write_u32(i as u32);
+            assert_eq!(h.finish(), h2.finish());
+        }
+
+        let mut h1 = hasher.clone();
+        h1.write_u64(1);
+        h1.write_u64(2);
+        let mut h2 = hasher.clone();
+        h2.write_u64(2);
+        h2.write_u64(1);
+        assert_ne!(h1.finish(), h2.finish());
+
+        // A word equal to `secret[0]` must not erase the words written before it
+        let secret0 = crate::generics::DEFAULT_SECRET[0];
+        let mut pairs = HashSet::new();
+        let mut wide = HashSet::new();
+        for i in 0..1000u64 {
+            let mut h = hasher.clone();
+            h.write_u64(i);
+            h.write_u64(secret0);
+            assert!(pairs.insert(h.finish()), "collision at ({}, secret[0])", i);
+
+            let mut h = hasher.clone();
+            h.write_u128(((i as u128) << 64) | secret0 as u128);
+            assert!(wide.insert(h.finish()), "collision at u128 {}", i);
+        }
+    }
+
+    #[test]
+    fn test_hash_reader() {
+        for (seed_and_len, result) in EXTENDED_TEST_VECTOR.iter() {
+            let input = &EXTENDED_TEST_VECTOR_BUFFER[..*seed_and_len];
+            let hasher = WyHash64::with_seed(*seed_and_len as u64);
+            assert_eq!(hasher.hash_reader(input).unwrap(), *result);
+        }
+        assert_eq!(
+            WyHash64::hash_reader(EXTENDED_TEST_VECTOR_BUFFER).unwrap(),
+            WyHash64::hash(EXTENDED_TEST_VECTOR_BUFFER)
+        );
+    }
+
+    #[test]
+    fn test_streamed_long_input() {
+        let input: Vec<u8> = (0..400u32).map(|i| (i * 31 + 7) as u8).collect();
+        let hasher = WyHash64::with_seed(42);
+        for len in 0..input.len() {
+            let input = &input[..len];
+            for chunksize in [1, 15, 16, 17, 47, 48, 49, 95, 96, 97, 144, 200] {
+                let mut streamed = hasher.streamed();
+                for chunk in input.chunks(chunksize) {
+                    streamed.write(chunk);
+                }
+                assert_eq!(
+                    streamed.finish(),
+                    hasher.hash(input),
+                    "len: {}, chunksize: {}",
+                    len,
+                    chunksize
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_resume_from_state() {
+        let input: Vec<u8> = (0..300u32).map(|i| (i * 13 + 5) as u8).collect();
+        let hasher = WyHash64::with_seed(7);
+        for split in 0..input.len() {
+            let mut streamed = hasher.streamed();
+            streamed.write(&input[..split]);
+            let state = streamed.to_state_bytes();
+
+            let mut resumed =
+                crate::generics::StreamedWyHasher::<WyHash64>::from_state_bytes(&state).unwrap();
+            resumed.write(&input[split..]);
+            assert_eq!(resumed.finish(), hasher.hash(&input), "split: {}", split);
+        }
+    }
+
+    #[test]
+    fn test_invalid_state() {
+        use crate::generics::{StateError, StreamedWyHasher, VariantTag};
+
+        let mut streamed = WyHash64::with_seed(7).streamed();
+        streamed.write(b"wyhash");
+        let state = streamed.to_state_bytes();
+
+        assert_eq!(
+            StreamedWyHasher::<WyHash64>::from_state_bytes(&state[..state.len() - 1]).err(),
+            Some(StateError::InvalidLength {
+                expected: state.len(),
+                found: state.len() - 1
+            })
+        );
+
+        let mut bad = state;
+        bad[0] = 0;
+        assert_eq!(
+            StreamedWyHasher::<WyHash64>::from_state_bytes(&bad).err(),
+            Some(StateError::UnsupportedVersion(0))
+        );
+
+        // A state of version 1 was 8 bytes shorter.
+        assert_eq!(
+            StreamedWyHasher::<WyHash64>::from_state_bytes(&[&[1u8][..], &state[1..115]].concat())
+                .err(),
+            Some(StateError::UnsupportedVersion(1))
+        );
+        assert_eq!(
+            StreamedWyHasher::<WyHash64>::from_state_bytes(&[]).err(),
+            Some(StateError::InvalidLength {
+                expected: state.len(),
+                found: 0
+            })
+        );
+
+        let mut bad = state;
+        bad[1] = WyHash64::ID + 1;
+        assert_eq!(
+            StreamedWyHasher::<WyHash64>::from_state_bytes(&bad).err(),
+            Some(StateError::VariantMismatch {
+                expected: WyHash64::ID,
+                found: WyHash64::ID + 1
+            })
+        );
+
+        let mut bad = state;
+        bad[66] = 49;
+        assert_eq!(
+            StreamedWyHasher::<WyHash64>::from_state_bytes(&bad).err(),
+            Some(StateError::Corrupted)
+        );
+    }
+
+    #[test]
+    fn test_clone_and_reset() {
+        let hasher = WyHash64::with_seed(3);
+        let mut streamed = hasher.streamed();
+        streamed.write(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+        let mut forked = streamed.clone();
+        forked.write(b"abcdefghijklmnopqrstuvwxyz0123456789");
+        streamed.write(b"0123456789");
+        assert_eq!(
+            forked.finish(),
+            hasher.hash(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789")
+        );
+        assert_eq!(
+            streamed.finish_reset(),
+            hasher.hash(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789")
+        );
+        assert_eq!(streamed.finish(), hasher.hash(b""));
+
+        forked.reset();
+        forked.write(b"wyhash");
+        assert_eq!(forked.finish(), hasher.hash(b"wyhash"));
+
+        let debug = format!("{:?}", forked);
+        assert!(!debug.contains(&format!("{}", 0xa0761d6478bd642fu64)));
+        assert_eq!(
+            crate::generics::StreamedWyHasher::<WyHash64>::default().finish(),
+            WyHash64::hash(b"")
+        );
+    }
+
+    #[test]
+    fn test_random_state() {
+        use crate::generics::WyRandomState;
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+
+        let state1 = WyRandomState::<WyHash64>::new();
+        let state2 = WyRandomState::<WyHash64>::new();
+        assert_eq!(state1.hash_one("wyhash"), state1.hash_one("wyhash"));
+        assert_ne!(state1.hash_one("wyhash"), state2.hash_one("wyhash"));
+
+        let mut map = HashMap::with_hasher(state1);
+        for i in 0..1000 {
+            map.insert(i, i * 2);
+        }
+        assert!((0..1000).all(|i| map[&i] == i * 2));
+    }
+
+    #[test]
+    fn test_hash_u64_pair() {
+        for (a, b, result) in HASH_U64_PAIR_TEST_VECTOR.iter().cloned() {
+            assert_eq!(WyHash64::hash_u64_pair(a, b), result);
+        }
+    }
+
+    #[test]
+    fn test_const_hash() {
+        const HASH: u64 = WyHash64::const_hash(b"wyhash", 3);
+        assert_eq!(HASH, TEST_VECTOR[3].2);
+
+        for (input, seed, result) in TEST_VECTOR.iter().cloned() {
+            assert_eq!(WyHash64::const_hash(input.as_bytes(), seed), result);
+        }
+        for (seed_and_len, result) in EXTENDED_TEST_VECTOR.iter().cloned() {
+            let input = &EXTENDED_TEST_VECTOR_BUFFER[..seed_and_len];
+            assert_eq!(WyHash64::const_hash(input, seed_and_len as u64), result);
+        }
+
+        let input: Vec<u8> = (0..400u32).map(|i| (i * 31 + 7) as u8).collect();
+        let secret = WyHash64::generate_secret(42);
+        let hasher = WyHash64::with_seed_and_secret(42, secret);
+        for len in 0..input.len() {
+            assert_eq!(
+                WyHash64::const_hash_with_secret(&input[..len], 42, secret),
+                hasher.hash(&input[..len])
+            );
+        }
+    }
+
+    #[test]
+    fn test_const_hasher() {
+        const SECRET: [u64; 4] = WyHash64::const_generate_secret(GENERATE_SECRET_TEST_VECTOR[9].0);
+        static HASHER: WyHasher<WyHash64> = WyHash64::const_with_seed_and_secret(7, SECRET);
+        static DEFAULT_HASHER: WyHasher<WyHash64> = WyHash64::const_with_seed(7);
+
+        assert_eq!(SECRET, GENERATE_SECRET_TEST_VECTOR[9].1);
+        for (seed, result) in GENERATE_SECRET_TEST_VECTOR.iter().cloned() {
+            assert_eq!(WyHash64::const_generate_secret(seed), result);
+        }
+        for input in EXTENDED_TEST_VECTOR_BUFFER.chunks(13) {
+            assert_eq!(
+                HASHER.hash(input),
+                WyHash64::with_seed_and_secret(7, SECRET).hash(input)
+            );
+            assert_eq!(
+                DEFAULT_HASHER.hash(input),
+                WyHash64::with_seed(7).hash(input)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_batch() {
+        let hasher = WyHash64::with_seed(5);
+        let inputs: Vec<&[u8]> = (0..=EXTENDED_TEST_VECTOR_BUFFER.len())
+            .map(|len| &EXTENDED_TEST_VECTOR_BUFFER[..len])
+            .collect();
+        let mut output = vec![0u64; inputs.len()];
+        hasher.hash_batch(&inputs, &mut output);
+        for (input, result) in inputs.iter().zip(output) {
+            assert_eq!(hasher.hash(input), result, "len: {}", input.len());
+        }
+    }
 }
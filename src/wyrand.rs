@@ -0,0 +1,385 @@
+use crate::generics::{wyrand, WyHashVariant};
+use core::marker::PhantomData;
+
+///
+/// The wyrand pseudo-random number generator, which passes BigCrush and PractRand.
+///
+/// The output is bit-compatible with `wyrand()` of the reference wyhash final4 implementation
+/// compiled with the settings corresponding to the variant `V`. Note that it's not suitable for
+/// cryptographic purposes.
+///
+#[derive(Clone, Debug)]
+pub struct WyRand<V: WyHashVariant> {
+    seed: u64,
+    _marker: PhantomData<V>,
+}
+
+impl<V: WyHashVariant> WyRand<V> {
+    ///
+    /// Create a new [`WyRand`] instance with given seed
+    ///
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            _marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Get the current state of this generator, from which an identical generator can be
+    /// created with [`Self::from_seed`]
+    ///
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    ///
+    /// Generate a random `u64`
+    ///
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        wyrand::<V>(&mut self.seed)
+    }
+
+    ///
+    /// Generate a random `u32`, which is the higher half of a random `u64`
+    ///
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    ///
+    /// Fill the given buffer with random bytes, each consecutive 8 bytes are taken from a random
+    /// `u64` in little-endian order
+    ///
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let len = remainder.len();
+            remainder.copy_from_slice(&self.next_u64().to_le_bytes()[..len]);
+        }
+    }
+
+    ///
+    /// Generate a uniformly distributed random `f64` in `[0, 1)`, see [`wy2u01`]
+    ///
+    #[inline]
+    pub fn next_u01(&mut self) -> f64 {
+        wy2u01(self.next_u64())
+    }
+
+    ///
+    /// Generate an approximately Gaussian distributed random `f64`, see [`wy2gau`]
+    ///
+    #[inline]
+    pub fn next_gau(&mut self) -> f64 {
+        wy2gau(self.next_u64())
+    }
+
+    ///
+    /// Generate a random integer in `[0, k)` quickly with slight bias
+    ///
+    /// The result is the high half of the full product of a random `u64` and `k`, which is the
+    /// same as [`wy2u0k`] for the `WyHash64` variant, but stays in range for every variant.
+    /// Use [`Self::next_below`] for unbiased results.
+    ///
+    #[inline]
+    pub fn next_u0k(&mut self, k: u64) -> u64 {
+        (((self.next_u64() as u128) * (k as u128)) >> 64) as u64
+    }
+
+    ///
+    /// Generate a uniformly distributed random integer in `[0, bound)`
+    ///
+    /// Panics if `bound` is zero.
+    ///
+    pub fn next_below(&mut self, bound: u64) -> u64 {
+        assert!(bound != 0, "bound must be greater than zero");
+        // Lemire's nearly divisionless method, rejecting the biased results
+        let mut m = (self.next_u64() as u128) * (bound as u128);
+        if (m as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (m as u64) < threshold {
+                m = (self.next_u64() as u128) * (bound as u128);
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    ///
+    /// Generate a uniformly distributed random integer in the given range
+    ///
+    /// Panics if the range is empty.
+    ///
+    pub fn next_in_range<R: core::ops::RangeBounds<u64>>(&mut self, range: R) -> u64 {
+        use core::ops::Bound;
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("empty range"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end,
+            Bound::Excluded(&end) => end.checked_sub(1).expect("empty range"),
+            Bound::Unbounded => u64::MAX,
+        };
+        assert!(start <= end, "empty range");
+
+        match (end - start).checked_add(1) {
+            Some(span) => start + self.next_below(span),
+            None => self.next_u64(),
+        }
+    }
+}
+
+///
+/// Convert a random `u64` to a uniformly distributed `f64` in `[0, 1)`, which is `_wy2u01` of
+/// the reference implementation
+///
+#[inline]
+pub fn wy2u01(r: u64) -> f64 {
+    const WYNORM: f64 = 1.0 / (1u64 << 52) as f64;
+    (r >> 12) as f64 * WYNORM
+}
+
+///
+/// Convert a random `u64` to an approximately Gaussian distributed `f64` in `[-3, 3)`, with mean
+/// 0 and standard deviation 1, which is `_wy2gau` of the reference implementation
+///
+#[inline]
+pub fn wy2gau(r: u64) -> f64 {
+    const WYNORM: f64 = 1.0 / (1u64 << 20) as f64;
+    ((r & 0x1fffff) + ((r >> 21) & 0x1fffff) + ((r >> 42) & 0x1fffff)) as f64 * WYNORM - 3.0
+}
+
+///
+/// Convert a random `u64` to an integer in `[0, k)` with a single multiplication, which is
+/// `_wy2u0k` of the reference implementation
+///
+/// The result is slightly biased, and it's only guaranteed to be less than `k` for
+/// the `WyHash64` variant, as the other variants alter the high half of the product when mixing.
+/// Use [`WyRand::next_below`] for unbiased results.
+///
+#[inline]
+pub fn wy2u0k<V: WyHashVariant>(r: u64, k: u64) -> u64 {
+    V::mul_mum(r, k).1
+}
+
+#[cfg(feature = "rand_core")]
+impl<V: WyHashVariant> rand_core::RngCore for WyRand<V> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        WyRand::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        WyRand::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        WyRand::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        WyRand::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<V: WyHashVariant> rand_core::SeedableRng for WyRand<V> {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        WyRand::from_seed(u64::from_le_bytes(seed))
+    }
+
+    ///
+    /// Create a new generator with `state` as the seed as is, so that the output is identical
+    /// to the reference implementation seeded with `state`
+    ///
+    fn seed_from_u64(state: u64) -> Self {
+        WyRand::from_seed(state)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test {
+    use super::*;
+
+    #[cfg(any(
+        feature = "wyhash32",
+        feature = "wyhash32condom",
+        feature = "wyhash64",
+        feature = "wyhash64condom"
+    ))]
+    const SEED: u64 = 0x1234;
+
+    const CONVERSION_INPUTS: [u64; 5] = [
+        0x0,
+        0x1,
+        0x99de4ab9ff919451,
+        0xffffffffffffffff,
+        0x123456789abcdef0,
+    ];
+
+    #[cfg(feature = "wyhash64")]
+    #[test]
+    fn test_wyhash64() {
+        let mut rng = WyRand::<crate::WyHash64>::from_seed(SEED);
+        let expected = [
+            0x99de4ab9ff919451u64,
+            0x12f3cff030acb98c,
+            0x999b16a79b4dff88,
+            0x70283c80510702f1,
+            0x59829b5239f8feb5,
+            0x9fcb790407e17372,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u64(), value);
+        }
+    }
+
+    #[cfg(feature = "wyhash64condom")]
+    #[test]
+    fn test_wyhash64condom() {
+        let mut rng = WyRand::<crate::WyHash64Condom>::from_seed(SEED);
+        let expected = [
+            0x7edd34685f25bc8au64,
+            0xf5f0b12190189157,
+            0x7e9868763bf9d753,
+            0x972b4251f1b32a2a,
+            0xbe81e583994cd66e,
+            0x78c807d5a7555ba9,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u64(), value);
+        }
+    }
+
+    #[cfg(feature = "wyhash32")]
+    #[test]
+    fn test_wyhash32() {
+        let mut rng = WyRand::<crate::WyHash32>::from_seed(SEED);
+        let expected = [
+            0x67e63a87e66e63bdu64,
+            0x833c2fedf793668b,
+            0x7674eeba66a3c1f2,
+            0xfaa3d33cae993c4e,
+            0xa66ff542e640a28e,
+            0xa0d48a78c81d8d19,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u64(), value);
+        }
+    }
+
+    #[cfg(feature = "wyhash32condom")]
+    #[test]
+    fn test_wyhash32condom() {
+        let mut rng = WyRand::<crate::WyHash32Condom>::from_seed(SEED);
+        let expected = [
+            0x80e5445646da4b66u64,
+            0x643f513c57274e50,
+            0x9177906bc617e929,
+            0x1da0aded0e2d1495,
+            0x416c8b9346f48a55,
+            0x47d7f4a968a9a5c2,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u64(), value);
+        }
+    }
+
+    #[cfg(feature = "wyhash64")]
+    #[test]
+    fn test_fill_bytes_and_ranges() {
+        let mut rng = WyRand::<crate::WyHash64>::from_seed(SEED);
+        let mut bytes = [0u8; 13];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(bytes[..8], 0x99de4ab9ff919451u64.to_le_bytes());
+        assert_eq!(bytes[8..], 0x12f3cff030acb98cu64.to_le_bytes()[..5]);
+        assert_eq!(rng.next_u32(), 0x999b16a7);
+
+        for _ in 0..1000 {
+            assert!(rng.next_below(7) < 7);
+            assert!((10..20).contains(&rng.next_in_range(10..20)));
+            assert!((10..=20).contains(&rng.next_in_range(10..=20)));
+            assert_eq!(rng.next_in_range(5..=5), 5);
+        }
+        rng.next_in_range(..);
+    }
+
+    #[cfg(all(feature = "wyhash64", feature = "rand_core"))]
+    #[test]
+    fn test_rand_core() {
+        use rand_core::{RngCore, SeedableRng};
+
+        fn generate<R: RngCore + SeedableRng>(seed: u64) -> [u64; 2] {
+            let mut rng = R::seed_from_u64(seed);
+            [rng.next_u64(), rng.next_u64()]
+        }
+
+        assert_eq!(
+            generate::<WyRand<crate::WyHash64>>(SEED),
+            [0x99de4ab9ff919451, 0x12f3cff030acb98c]
+        );
+        let mut rng = <WyRand<crate::WyHash64> as SeedableRng>::from_seed(SEED.to_le_bytes());
+        let mut bytes = [0u8; 8];
+        rng.try_fill_bytes(&mut bytes).unwrap();
+        assert_eq!(bytes, 0x99de4ab9ff919451u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_wy2u01_and_wy2gau() {
+        let expected = [
+            (0.0, -3.0),
+            (0.0, -2.9999990463256836),
+            (0.6010481552216957, -0.1338205337524414),
+            (0.9999999999999998, 2.999997138977051),
+            (0.0711111111111109, 0.32442665100097656),
+        ];
+        for (r, (u01, gau)) in CONVERSION_INPUTS.iter().zip(expected) {
+            assert_eq!(wy2u01(*r), u01);
+            assert_eq!(wy2gau(*r), gau);
+        }
+    }
+
+    #[cfg(feature = "wyhash64")]
+    #[test]
+    fn test_wy2u0k() {
+        let expected = [0x0, 0x0, 0x259, 0x3e7, 0x47];
+        for (r, result) in CONVERSION_INPUTS.iter().zip(expected) {
+            assert_eq!(wy2u0k::<crate::WyHash64>(*r, 1000), result);
+        }
+
+        let mut rng = WyRand::<crate::WyHash64>::from_seed(SEED);
+        assert_eq!(rng.next_u0k(1000), 0x259);
+        assert_eq!(rng.next_u01(), wy2u01(0x12f3cff030acb98c));
+        assert_eq!(rng.next_gau(), wy2gau(0x999b16a79b4dff88));
+    }
+
+    #[cfg(feature = "wyhash64condom")]
+    #[test]
+    fn test_wy2u0k_condom() {
+        let expected = [0x3e8, 0x3e8, 0x1b1, 0xf, 0x3af];
+        for (r, result) in CONVERSION_INPUTS.iter().zip(expected) {
+            assert_eq!(wy2u0k::<crate::WyHash64Condom>(*r, 1000), result);
+        }
+
+        let mut rng = WyRand::<crate::WyHash64Condom>::from_seed(SEED);
+        for k in [1, 2, 1000, u64::MAX] {
+            for _ in 0..1000 {
+                assert!(rng.next_u0k(k) < k);
+            }
+        }
+    }
+}